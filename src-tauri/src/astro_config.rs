@@ -0,0 +1,345 @@
+use crate::models::{AstroProjectConfig, I18nConfig};
+use crate::parser::remove_comments;
+use log::{debug, warn};
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+/// Extensions Astro accepts for `astro.config.*`, in the order it looks for them
+pub const ASTRO_CONFIG_EXTENSIONS: [&str; 5] = ["mjs", "js", "ts", "mts", "cjs"];
+
+/// Returns the project's `astro.config.*` file, if it has one
+pub fn find_astro_config(project_path: &Path) -> Option<PathBuf> {
+    ASTRO_CONFIG_EXTENSIONS
+        .iter()
+        .map(|ext| project_path.join(format!("astro.config.{ext}")))
+        .find(|path| path.is_file())
+}
+
+/// Reads the project's Astro config, falling back to Astro's defaults for anything
+/// missing or not statically readable
+pub fn load_astro_project_config(project_path: &Path) -> AstroProjectConfig {
+    let Some(config_path) = find_astro_config(project_path) else {
+        return AstroProjectConfig::default();
+    };
+
+    match std::fs::read_to_string(&config_path) {
+        Ok(content) => {
+            debug!(
+                "Astro Editor [ASTRO_CONFIG] Reading {}",
+                config_path.display()
+            );
+            AstroProjectConfig {
+                config_file: Some(config_path),
+                ..parse_astro_project_config(&content)
+            }
+        }
+        Err(e) => {
+            warn!(
+                "Astro Editor [ASTRO_CONFIG] Failed to read {}: {e}",
+                config_path.display()
+            );
+            AstroProjectConfig {
+                config_file: Some(config_path),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// Parses the object passed to `defineConfig` (or default-exported directly).
+/// Only literal values are understood - anything computed keeps Astro's default.
+pub fn parse_astro_project_config(content: &str) -> AstroProjectConfig {
+    let mut config = AstroProjectConfig::default();
+    let clean_content = remove_comments(content);
+
+    let Some(object) = config_object(&clean_content) else {
+        return config;
+    };
+
+    for (key, value) in object_entries(object) {
+        match key.as_str() {
+            "srcDir" => {
+                if let Some(dir) = path_value(value) {
+                    config.src_dir = normalize_dir(&dir);
+                }
+            }
+            "publicDir" => {
+                if let Some(dir) = path_value(value) {
+                    config.public_dir = normalize_dir(&dir);
+                }
+            }
+            "base" => config.base = string_value(value),
+            "site" => config.site = string_value(value),
+            "trailingSlash" => config.trailing_slash = string_value(value),
+            "i18n" => config.i18n = parse_i18n(value),
+            "integrations" => config.integrations = parse_integrations(value),
+            _ => {}
+        }
+    }
+
+    config
+}
+
+fn parse_i18n(value: &str) -> Option<I18nConfig> {
+    let entries = object_value(value)?;
+    let mut i18n = I18nConfig::default();
+
+    for (key, value) in entries {
+        match key.as_str() {
+            "defaultLocale" => i18n.default_locale = string_value(value),
+            "locales" => {
+                // Locales are either codes or `{ path, codes }` objects
+                i18n.locales = array_value(value)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|item| {
+                        string_value(item).or_else(|| {
+                            object_value(item)?
+                                .into_iter()
+                                .find(|(key, _)| key == "path")
+                                .and_then(|(_, path)| string_value(path))
+                        })
+                    })
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+
+    Some(i18n)
+}
+
+fn parse_integrations(value: &str) -> Vec<String> {
+    let call_re = Regex::new(r"^([A-Za-z_$][\w$.]*)\s*\(").unwrap();
+
+    array_value(value)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|item| call_re.captures(item).map(|caps| caps[1].to_string()))
+        .collect()
+}
+
+/// The body of the config object, without its braces
+fn config_object(content: &str) -> Option<&str> {
+    let start_re = Regex::new(r"defineConfig\s*\(\s*\{|export\s+default\s*\{").unwrap();
+    let open = start_re.find(content)?.end() - 1;
+    let close = matching_bracket(content, open)?;
+    Some(&content[open + 1..close])
+}
+
+/// Calls `visit` with the byte index, character and bracket depth of each character
+/// outside string literals, until it returns false. Brackets are visited at the depth
+/// outside them.
+fn visit_code(src: &str, mut visit: impl FnMut(usize, char, usize) -> bool) {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for (i, ch) in src.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        let keep_going = match ch {
+            '"' | '\'' | '`' => {
+                quote = Some(ch);
+                true
+            }
+            '(' | '[' | '{' => {
+                let keep_going = visit(i, ch, depth);
+                depth += 1;
+                keep_going
+            }
+            ')' | ']' | '}' => {
+                depth = depth.saturating_sub(1);
+                visit(i, ch, depth)
+            }
+            _ => visit(i, ch, depth),
+        };
+
+        if !keep_going {
+            return;
+        }
+    }
+}
+
+fn matching_bracket(src: &str, open: usize) -> Option<usize> {
+    let mut close = None;
+    visit_code(&src[open..], |i, ch, depth| {
+        if i > 0 && depth == 0 && matches!(ch, ')' | ']' | '}') {
+            close = Some(open + i);
+            return false;
+        }
+        true
+    });
+    close
+}
+
+/// Splits on commas that aren't nested in brackets or strings
+fn split_top_level(src: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    visit_code(src, |i, ch, depth| {
+        if ch == ',' && depth == 0 {
+            parts.push(&src[start..i]);
+            start = i + 1;
+        }
+        true
+    });
+    parts.push(&src[start..]);
+
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// `key: value` pairs of an object body. Shorthand properties and spreads are skipped.
+fn object_entries(body: &str) -> Vec<(String, &str)> {
+    split_top_level(body)
+        .into_iter()
+        .filter_map(|entry| {
+            let mut colon = None;
+            visit_code(entry, |i, ch, depth| {
+                if ch == ':' && depth == 0 {
+                    colon = Some(i);
+                    return false;
+                }
+                true
+            });
+            let colon = colon?;
+            let key = entry[..colon].trim().trim_matches(['"', '\'']);
+            Some((key.to_string(), entry[colon + 1..].trim()))
+        })
+        .collect()
+}
+
+fn object_value(value: &str) -> Option<Vec<(String, &str)>> {
+    let body = value.trim().strip_prefix('{')?.strip_suffix('}')?;
+    Some(object_entries(body))
+}
+
+fn array_value(value: &str) -> Option<Vec<&str>> {
+    let body = value.trim().strip_prefix('[')?.strip_suffix(']')?;
+    Some(split_top_level(body))
+}
+
+/// A plain string literal (template literals only without substitutions)
+fn string_value(value: &str) -> Option<String> {
+    let value = value.trim();
+    let quote = value
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '"' | '\'' | '`'))?;
+    let inner = value.strip_prefix(quote)?.strip_suffix(quote)?;
+    if quote == '`' && inner.contains("${") {
+        return None;
+    }
+    Some(inner.to_string())
+}
+
+/// A string literal, or the path of `new URL('./path', import.meta.url)`
+fn path_value(value: &str) -> Option<String> {
+    string_value(value).or_else(|| {
+        let url_re = Regex::new(r#"^new\s+URL\s*\(\s*['"]([^'"]*)['"]"#).unwrap();
+        url_re
+            .captures(value.trim())
+            .map(|caps| caps[1].to_string())
+    })
+}
+
+/// `./src/` -> `src`, `.` -> `` (the project root)
+fn normalize_dir(dir: &str) -> String {
+    let mut dir = dir.trim();
+    while let Some(rest) = dir.strip_prefix("./") {
+        dir = rest;
+    }
+    let dir = dir.trim_end_matches('/');
+    if dir == "." {
+        String::new()
+    } else {
+        dir.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_astro_project_config() {
+        let config = parse_astro_project_config(
+            r#"
+import { defineConfig } from 'astro/config';
+import mdx from '@astrojs/mdx';
+import starlight from "@astrojs/starlight";
+
+// srcDir: './commented-out'
+export default defineConfig({
+  site: 'https://example.com',
+  base: "/docs",
+  trailingSlash: 'always',
+  srcDir: './app/',
+  publicDir: new URL('./static', import.meta.url),
+  i18n: {
+    defaultLocale: 'en',
+    locales: ['en', 'fr', { path: 'spanish', codes: ['es', 'es-MX'] }],
+  },
+  integrations: [
+    mdx(),
+    starlight({ title: 'Docs, with a comma', sidebar: [{ label: 'Guides' }] }),
+    ...extraIntegrations,
+  ],
+  vite: { build: { srcDir: 'ignored' } },
+});
+"#,
+        );
+
+        assert_eq!(config.site.as_deref(), Some("https://example.com"));
+        assert_eq!(config.base.as_deref(), Some("/docs"));
+        assert_eq!(config.trailing_slash.as_deref(), Some("always"));
+        assert_eq!(config.src_dir, "app");
+        assert_eq!(config.public_dir, "static");
+        assert_eq!(
+            config.i18n,
+            Some(I18nConfig {
+                default_locale: Some("en".to_string()),
+                locales: vec!["en".to_string(), "fr".to_string(), "spanish".to_string()],
+            })
+        );
+        assert_eq!(config.integrations, vec!["mdx", "starlight"]);
+    }
+
+    #[test]
+    fn test_load_astro_project_config_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path();
+
+        // No config file at all
+        assert_eq!(
+            load_astro_project_config(project),
+            AstroProjectConfig::default()
+        );
+
+        // Computed values keep the defaults
+        fs::write(
+            project.join("astro.config.ts"),
+            "export default { srcDir: process.env.SRC_DIR, integrations: [] }",
+        )
+        .unwrap();
+        let config = load_astro_project_config(project);
+        assert_eq!(config.config_file, Some(project.join("astro.config.ts")));
+        assert_eq!(config.src_dir, "src");
+        assert_eq!(config.content_directory(), "src/content");
+    }
+}
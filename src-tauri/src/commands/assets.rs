@@ -0,0 +1,1122 @@
+use crate::astro_config::load_astro_project_config;
+use crate::commands::files::to_kebab_case;
+use crate::commands::images::IMAGE_EXTENSIONS;
+use crate::commands::links::{extract_links, lines_outside_code_blocks};
+use crate::commands::project::{scan_collection_files, scan_project_with_content_dir};
+use crate::commands::watcher::{content_hash, forget_own_changes, record_own_removal};
+use crate::models::Collection;
+use crate::parser::remove_comments;
+use crate::paths::{normalize_path, relative_path, to_slash_path};
+use chrono::Local;
+use ignore::WalkBuilder;
+use log::{info, warn};
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+
+/// Assets are stored per collection inside the assets directory by default
+pub const DEFAULT_FOLDER_TEMPLATE: &str = "{collection}";
+
+/// `2024-01-01-photo.png`
+pub const DEFAULT_FILENAME_TEMPLATE: &str = "{date}-{name}.{ext}";
+
+const TEMPLATE_TOKENS: [&str; 6] = ["collection", "slug", "date", "hash", "name", "ext"];
+
+/// Files besides images that the asset report looks at. Other files in `public/` (robots.txt,
+/// manifests, redirects) are used by the site without being referenced, so they're left out.
+const MEDIA_EXTENSIONS: [&str; 10] = [
+    "mp4", "webm", "mov", "mp3", "wav", "ogg", "m4a", "pdf", "zip", "csv",
+];
+
+/// Files outside the content directory that can reference assets
+const SOURCE_EXTENSIONS: [&str; 11] = [
+    "astro", "ts", "tsx", "js", "jsx", "mjs", "vue", "svelte", "css", "scss", "json",
+];
+
+/// A file imported into the assets directory
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportedAsset {
+    pub path: String,       // Path to write into the document
+    pub deduplicated: bool, // An identical file was already in the assets directory, so nothing was copied
+}
+
+/// Content hashes of existing assets, so repeated imports don't re-read every file
+static ASSET_INDEX: OnceLock<Mutex<HashMap<PathBuf, IndexedAsset>>> = OnceLock::new();
+
+struct IndexedAsset {
+    len: u64,
+    modified: Option<SystemTime>,
+    hash: String,
+}
+
+fn asset_index() -> &'static Mutex<HashMap<PathBuf, IndexedAsset>> {
+    ASSET_INDEX.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Finds a file under `dir` with the given content hash. Only files of the same size are
+/// hashed, and hashes are cached until a file's size or modification time changes.
+pub fn find_duplicate_asset(dir: &Path, len: u64, hash: &str) -> Option<PathBuf> {
+    let mut candidates: Vec<PathBuf> = WalkBuilder::new(dir)
+        .standard_filters(false)
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+        .filter(|entry| entry.metadata().is_ok_and(|metadata| metadata.len() == len))
+        .map(|entry| entry.into_path())
+        .collect();
+    candidates.sort();
+
+    candidates
+        .into_iter()
+        .find(|candidate| indexed_hash(candidate).as_deref() == Some(hash))
+}
+
+fn indexed_hash(path: &Path) -> Option<String> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let modified = metadata.modified().ok();
+
+    let cached = asset_index()
+        .lock()
+        .unwrap()
+        .get(path)
+        .filter(|indexed| indexed.len == metadata.len() && indexed.modified == modified)
+        .map(|indexed| indexed.hash.clone());
+    if cached.is_some() {
+        return cached;
+    }
+
+    let hash = content_hash(&std::fs::read(path).ok()?);
+    index_asset(path, &hash);
+    Some(hash)
+}
+
+/// Adds an asset's hash to the index
+pub fn index_asset(path: &Path, hash: &str) {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return;
+    };
+    asset_index().lock().unwrap().insert(
+        path.to_path_buf(),
+        IndexedAsset {
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+            hash: hash.to_string(),
+        },
+    );
+}
+
+/// Where a copied asset goes and what it's called. A folder template starting with `./`
+/// is relative to the target document, for assets co-located with their post.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetNaming {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_template: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filename_template: Option<String>,
+}
+
+/// Values for the tokens of a naming template
+#[derive(Debug, Clone)]
+pub struct TemplateTokens {
+    pub collection: String,
+    pub slug: Option<String>, // Only known when copying for a document
+    pub date: String,
+    pub hash: String,
+    pub name: String,
+    pub ext: String,
+}
+
+impl TemplateTokens {
+    pub fn new(
+        source: &Path,
+        content_hash: &str,
+        collection: &str,
+        document: Option<&Path>,
+    ) -> Self {
+        let file_name = source
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let ext = source
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("")
+            .to_string();
+        let name_without_ext = file_name.trim_end_matches(&format!(".{ext}"));
+
+        Self {
+            collection: collection.to_string(),
+            slug: document.and_then(document_slug),
+            date: Local::now().format("%Y-%m-%d").to_string(),
+            hash: content_hash.chars().take(8).collect(),
+            name: to_kebab_case(name_without_ext),
+            ext,
+        }
+    }
+}
+
+/// `blog/my-post.md` and `blog/my-post/index.md` both have the slug `my-post`
+fn document_slug(document: &Path) -> Option<String> {
+    let stem = document.file_stem()?.to_str()?;
+    let name = if stem == "index" {
+        document.parent()?.file_name()?.to_str()?
+    } else {
+        stem
+    };
+    Some(to_kebab_case(name))
+}
+
+/// Checks a template only uses known tokens; filenames can't contain folders
+pub fn validate_template(template: &str, is_filename: bool) -> Result<(), String> {
+    if template.trim().is_empty() {
+        return Err("template must not be empty".to_string());
+    }
+    if is_filename && template.contains(['/', '\\']) {
+        return Err(format!(
+            "filename template '{template}' must not contain folders"
+        ));
+    }
+    for captures in token_regex().captures_iter(template) {
+        let token = &captures[1];
+        if !TEMPLATE_TOKENS.contains(&token) {
+            return Err(format!(
+                "unknown token {{{token}}} in '{template}', expected one of {}",
+                TEMPLATE_TOKENS
+                    .map(|token| format!("{{{token}}}"))
+                    .join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Fills in a naming template. The result never leaves the folder it's resolved against.
+pub fn render_template(template: &str, tokens: &TemplateTokens) -> Result<String, String> {
+    let mut error = None;
+    let rendered = token_regex().replace_all(template, |captures: &Captures| {
+        let value = match &captures[1] {
+            "collection" => Some(tokens.collection.clone()),
+            "slug" => tokens.slug.clone(),
+            "date" => Some(tokens.date.clone()),
+            "hash" => Some(tokens.hash.clone()),
+            "name" => Some(tokens.name.clone()),
+            "ext" => Some(tokens.ext.clone()),
+            _ => None,
+        };
+        value.unwrap_or_else(|| {
+            error.get_or_insert_with(|| match &captures[1] {
+                "slug" => "The {slug} token needs a target document".to_string(),
+                token => format!("Unknown token {{{token}}} in asset template"),
+            });
+            String::new()
+        })
+    });
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    // `{name}.{ext}` for a file without an extension
+    let rendered = rendered.trim_end_matches('.');
+    let escapes = Path::new(rendered)
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir));
+    if escapes {
+        return Err(format!(
+            "Asset template '{template}' points outside its folder"
+        ));
+    }
+
+    Ok(rendered.to_string())
+}
+
+struct AssetPatterns {
+    token: Regex,
+    trailing_commas: Regex,
+    tag: Regex,
+    import: Regex,
+    attribute: Regex,
+    data_value: Regex,
+}
+
+static ASSET_PATTERNS: OnceLock<AssetPatterns> = OnceLock::new();
+
+fn asset_patterns() -> &'static AssetPatterns {
+    ASSET_PATTERNS.get_or_init(|| AssetPatterns {
+        token: Regex::new(r"\{(\w+)\}").unwrap(),
+        trailing_commas: Regex::new(r",(\s*[}\]])").unwrap(),
+        tag: Regex::new(r"<(img|Image|Picture)\s([^>]*)>").unwrap(),
+        import: Regex::new(r#"^import\s+[\w{}\s,*]+\s+from\s+["']([^"']+)["']"#).unwrap(),
+        attribute: Regex::new(
+            r#"(?:^|\s)([\w:-]+)\s*=\s*(?:"([^"]*)"|'([^']*)'|\{\s*["'`]([^"'`]*)["'`]\s*\}|(\{[^}]*\}))"#,
+        )
+        .unwrap(),
+        data_value: Regex::new(r#""([^"\n]*)"|'([^'\n]*)'|([^\s"',:=\[\]{}#]+)"#).unwrap(),
+    })
+}
+
+fn token_regex() -> &'static Regex {
+    &asset_patterns().token
+}
+
+/// The first free path for `file_name` in `dir`, appending -1, -2, etc. before the extension
+pub fn unique_asset_path(dir: &Path, file_name: &str, ext: &str) -> PathBuf {
+    let stem = file_name
+        .strip_suffix(&format!(".{ext}"))
+        .filter(|_| !ext.is_empty());
+    let mut path = dir.join(file_name);
+    let mut counter = 1;
+
+    while path.exists() {
+        let name_with_counter = match stem {
+            Some(stem) => format!("{stem}-{counter}.{ext}"),
+            None => format!("{file_name}-{counter}"),
+        };
+        path = dir.join(name_with_counter);
+        counter += 1;
+    }
+
+    path
+}
+
+/// How the path of a copied asset is written into a document
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetPathStyle {
+    /// Relative to the project root: `src/assets/blog/photo.png`
+    #[default]
+    ProjectRoot,
+    /// Relative to the document, as Astro's `image()` fields and Markdown images expect:
+    /// `../../assets/blog/photo.png`
+    Relative,
+    /// Through an import alias from tsconfig.json `paths`: `@assets/blog/photo.png`
+    Alias,
+    /// The URL a file in `public/` is served at, including the site's `base`: `/images/photo.png`
+    PublicUrl,
+}
+
+/// Formats the path of an asset for use in a document, in the given style. `asset` and
+/// `document` must be canonical paths inside `project_root`.
+pub fn asset_reference(
+    project_root: &Path,
+    asset: &Path,
+    style: AssetPathStyle,
+    document: Option<&Path>,
+) -> Result<String, String> {
+    let from_root = asset
+        .strip_prefix(project_root)
+        .map_err(|_| "Asset is outside the project directory".to_string())?;
+
+    match style {
+        AssetPathStyle::ProjectRoot => Ok(to_slash_path(from_root)),
+        AssetPathStyle::Relative => {
+            let document_dir = document
+                .and_then(Path::parent)
+                .ok_or("A target document is required for relative asset paths")?;
+            let relative = to_slash_path(&relative_path(document_dir, asset));
+            if relative.starts_with("../") {
+                Ok(relative)
+            } else {
+                Ok(format!("./{relative}"))
+            }
+        }
+        AssetPathStyle::Alias => alias_path(project_root, asset).ok_or_else(|| {
+            "No import alias in tsconfig.json points to the assets directory".to_string()
+        }),
+        AssetPathStyle::PublicUrl => {
+            let config = load_astro_project_config(project_root);
+            let in_public = from_root
+                .strip_prefix(&config.public_dir)
+                .map_err(|_| format!("Asset is not in the {} directory", config.public_dir))?;
+
+            let base = config.base.as_deref().unwrap_or_default().trim_matches('/');
+            let path = to_slash_path(in_public).replace(' ', "%20");
+            if base.is_empty() {
+                Ok(format!("/{path}"))
+            } else {
+                Ok(format!("/{base}/{path}"))
+            }
+        }
+    }
+}
+
+/// Rewrites an asset path through the most specific wildcard alias in tsconfig.json
+/// `compilerOptions.paths`, e.g. `"@assets/*": ["src/assets/*"]`
+fn alias_path(project_root: &Path, asset: &Path) -> Option<String> {
+    tsconfig_aliases(project_root)
+        .into_iter()
+        .filter_map(|(alias_prefix, target_dir)| {
+            let rest = asset.strip_prefix(&target_dir).ok()?;
+            Some((
+                target_dir.components().count(),
+                format!("{alias_prefix}{}", to_slash_path(rest)),
+            ))
+        })
+        .max_by_key(|(depth, _)| *depth)
+        .map(|(_, path)| path)
+}
+
+/// Wildcard aliases from tsconfig.json `compilerOptions.paths`, as the alias without its
+/// `*` and the folder it points to
+fn tsconfig_aliases(project_root: &Path) -> Vec<(String, PathBuf)> {
+    let Ok(content) = std::fs::read_to_string(project_root.join("tsconfig.json")) else {
+        return Vec::new();
+    };
+    // tsconfig.json allows comments and trailing commas
+    let content = remove_comments(&content);
+    let content = asset_patterns().trailing_commas.replace_all(&content, "$1");
+    let Ok(tsconfig) = serde_json::from_str::<Value>(&content) else {
+        return Vec::new();
+    };
+
+    let options = tsconfig.get("compilerOptions");
+    let base_url = project_root.join(
+        options
+            .and_then(|options| options.get("baseUrl"))
+            .and_then(Value::as_str)
+            .unwrap_or("."),
+    );
+    let paths = options
+        .and_then(|options| options.get("paths"))
+        .and_then(Value::as_object);
+
+    let mut aliases = Vec::new();
+    for (alias, targets) in paths.into_iter().flatten() {
+        let Some(alias_prefix) = alias.strip_suffix('*') else {
+            continue;
+        };
+        for target in targets.as_array().into_iter().flatten() {
+            if let Some(target_prefix) = target.as_str().and_then(|t| t.strip_suffix('*')) {
+                aliases.push((
+                    alias_prefix.to_string(),
+                    normalize_path(&base_url.join(target_prefix)),
+                ));
+            }
+        }
+    }
+    aliases
+}
+
+/// A file reference found in the body of a Markdown or MDX document
+#[derive(Debug, Clone, PartialEq)]
+pub struct BodyReference {
+    pub target: String, // As written; `{expression}` for a JSX `src={...}`
+    pub is_image: bool,
+    pub alt: Option<String>, // Images only; `None` when there is no alt text at all
+    pub line: usize,         // 1-based
+}
+
+/// Finds images, links, `<img>`/`<Image>`/`<Picture>` tags, `<a href>` tags and MDX
+/// imports outside code blocks
+pub fn extract_body_references(content: &str) -> Vec<BodyReference> {
+    let patterns = asset_patterns();
+
+    let mut references: Vec<BodyReference> = extract_links(content)
+        .into_iter()
+        .map(|link| BodyReference {
+            target: link.target.to_string(),
+            is_image: link.is_image,
+            alt: link.is_image.then(|| link.text.to_string()),
+            line: link.line,
+        })
+        .collect();
+
+    for (line_number, line) in lines_outside_code_blocks(content) {
+        for cap in patterns.tag.captures_iter(line) {
+            let Some(target) = tag_attribute(&cap[2], "src") else {
+                continue;
+            };
+            references.push(BodyReference {
+                target,
+                is_image: true,
+                alt: tag_attribute(&cap[2], "alt"),
+                line: line_number,
+            });
+        }
+        // Only files are imported by path; packages and components are resolved elsewhere
+        if let Some(cap) = patterns.import.captures(line).filter(|cap| {
+            cap[1].starts_with('.') || cap[1].starts_with('@') || cap[1].starts_with('/')
+        }) {
+            references.push(BodyReference {
+                target: cap[1].to_string(),
+                is_image: false,
+                alt: None,
+                line: line_number,
+            });
+        }
+    }
+
+    // In document order; the sort is stable, so references on one line keep their order
+    references.sort_by_key(|reference| reference.line);
+    references
+}
+
+/// Reads `name="value"`, `name='value'`, `name={"value"}` or `name={expression}` from a
+/// tag's attributes
+fn tag_attribute(attributes: &str, name: &str) -> Option<String> {
+    let cap = asset_patterns()
+        .attribute
+        .captures_iter(attributes)
+        .find(|cap| &cap[1] == name)?;
+    (2..=5).find_map(|group| cap.get(group).map(|m| m.as_str().to_string()))
+}
+
+/// Resolves references to files the way Astro and Vite do: relative to the document,
+/// through tsconfig.json aliases, from `public/` for absolute URLs, or from the project root
+pub struct AssetResolver {
+    project_root: PathBuf,
+    public_dir: PathBuf,
+    base: String, // Without slashes
+    aliases: Vec<(String, PathBuf)>,
+}
+
+impl AssetResolver {
+    pub fn new(project_root: &Path) -> Self {
+        let config = load_astro_project_config(project_root);
+        let mut aliases = tsconfig_aliases(project_root);
+        // Longest alias first, so `@assets/` wins over `@/`
+        aliases.sort_by_key(|(alias, _)| std::cmp::Reverse(alias.len()));
+
+        Self {
+            project_root: project_root.to_path_buf(),
+            public_dir: project_root.join(&config.public_dir),
+            base: config
+                .base
+                .unwrap_or_default()
+                .trim_matches('/')
+                .to_string(),
+            aliases,
+        }
+    }
+
+    /// The file a reference points to, which may not exist. `None` for URLs, anchors and
+    /// JSX expressions.
+    pub fn resolve(&self, target: &str, document: Option<&Path>) -> Option<PathBuf> {
+        let target = target.trim();
+        let is_path = !target.is_empty()
+            && !target.starts_with(['{', '#', '?'])
+            && !target.starts_with("//")
+            && !target.contains("://")
+            && !target.starts_with("data:")
+            && !target.starts_with("mailto:")
+            && !target.starts_with("tel:");
+        if !is_path {
+            return None;
+        }
+
+        let path = target.split(['?', '#']).next().unwrap_or(target);
+        let path = percent_decode(path);
+        let document_dir = document.and_then(Path::parent);
+
+        if path.starts_with("./") || path.starts_with("../") {
+            return Some(normalize_path(&document_dir?.join(&path)));
+        }
+
+        if let Some(absolute) = path.strip_prefix('/') {
+            let in_site = absolute
+                .strip_prefix(&self.base)
+                .filter(|_| !self.base.is_empty())
+                .map_or(absolute, |rest| rest.trim_start_matches('/'));
+            let public = normalize_path(&self.public_dir.join(in_site));
+            // Project-root paths like `/src/assets/...` were written by older versions
+            let from_root = normalize_path(&self.project_root.join(absolute));
+            return Some(if !public.exists() && from_root.exists() {
+                from_root
+            } else {
+                public
+            });
+        }
+
+        if let Some((alias, dir)) = self
+            .aliases
+            .iter()
+            .find(|(alias, _)| !alias.is_empty() && path.starts_with(alias.as_str()))
+        {
+            return Some(normalize_path(&dir.join(&path[alias.len()..])));
+        }
+
+        // Bare paths are relative to the document in Markdown, but older versions wrote
+        // them relative to the project root
+        let from_document = document_dir.map(|dir| normalize_path(&dir.join(&path)));
+        let from_root = normalize_path(&self.project_root.join(&path));
+        match from_document {
+            Some(from_document) if from_document.exists() || !from_root.exists() => {
+                Some(from_document)
+            }
+            _ => Some(from_root),
+        }
+    }
+}
+
+/// Decodes `%20`-style escapes; invalid escapes are kept as written
+fn percent_decode(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| path.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+/// Assets no document uses, and references to files that don't exist
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AssetReport {
+    pub assets_checked: usize,
+    pub orphans: Vec<OrphanedAsset>,
+    pub orphaned_size: u64, // Total bytes of all orphans
+    pub missing: Vec<MissingAsset>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrphanedAsset {
+    pub path: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MissingAsset {
+    pub document: String,
+    pub reference: String,   // As written in the document
+    pub line: Option<usize>, // `None` for frontmatter references
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct AssetDeletionResult {
+    pub deleted: Vec<String>,
+    pub failed: Vec<AssetDeletionFailure>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssetDeletionFailure {
+    pub path: String,
+    pub error: String,
+}
+
+/// Cross-references the images and media files in the assets directory, `public/` and
+/// content collections against the references in every document's body and frontmatter
+#[tauri::command]
+pub async fn find_orphaned_assets(
+    project_path: String,
+    content_directory: Option<String>,
+    assets_directory: Option<String>,
+) -> Result<AssetReport, String> {
+    let project_root = Path::new(&project_path)
+        .canonicalize()
+        .map_err(|_| "Invalid project root".to_string())?;
+    let resolver = AssetResolver::new(&project_root);
+    let config = load_astro_project_config(&project_root);
+    let content_dir = project_root.join(
+        content_directory
+            .clone()
+            .unwrap_or_else(|| config.content_directory()),
+    );
+    let collections = scan_project_with_content_dir(
+        project_root.to_string_lossy().to_string(),
+        content_directory,
+    )
+    .await?;
+
+    let mut report = AssetReport::default();
+    let mut referenced: HashSet<PathBuf> = HashSet::new();
+    let mut record_references = |document: &Path, references: Vec<(String, Option<usize>)>| {
+        for (reference, line) in references {
+            let Some(path) = resolver.resolve(&reference, Some(document)) else {
+                continue;
+            };
+            if path.is_file() {
+                referenced.insert(path);
+            } else {
+                report.missing.push(MissingAsset {
+                    document: document.to_string_lossy().to_string(),
+                    reference,
+                    line,
+                });
+            }
+        }
+    };
+
+    for collection in &collections {
+        let files =
+            scan_collection_files(collection.path.to_string_lossy().to_string(), None).await?;
+
+        for file in files {
+            let Ok(content) = std::fs::read_to_string(&file.path) else {
+                continue;
+            };
+
+            let mut references: Vec<(String, Option<usize>)> = extract_body_references(&content)
+                .into_iter()
+                .filter(|reference| reference.is_image || is_file_reference(&reference.target))
+                .map(|reference| (reference.target, Some(reference.line)))
+                .collect();
+            if let Some(frontmatter) = &file.frontmatter {
+                let mut values = Vec::new();
+                for value in frontmatter.values() {
+                    collect_strings(value, &mut values);
+                }
+                references.extend(
+                    values
+                        .into_iter()
+                        .filter(|value| value.contains('/') && is_file_reference(value))
+                        .map(|value| (value, None)),
+                );
+            }
+            record_references(&file.path, references);
+        }
+    }
+
+    // Data collections (`authors/*.json`) and other files next to the entries reference
+    // assets through their values, just like frontmatter
+    let mut data_files: Vec<PathBuf> = std::iter::once(&content_dir)
+        .chain(collections.iter().map(|collection| &collection.path))
+        .flat_map(|dir| WalkBuilder::new(dir).build())
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+        .map(|entry| entry.into_path())
+        .filter(|path| {
+            let is_entry = matches!(
+                path.extension().and_then(|ext| ext.to_str()),
+                Some("md") | Some("mdx")
+            );
+            !is_entry && !is_media_file(path)
+        })
+        .collect();
+    data_files.sort();
+    data_files.dedup();
+    for path in data_files {
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let references = data_file_strings(&content)
+            .into_iter()
+            .filter(|value| value.contains('/') && is_file_reference(value))
+            .map(|value| (value, None))
+            .collect();
+        record_references(&path, references);
+    }
+
+    let assets_dir =
+        project_root.join(assets_directory.unwrap_or_else(|| config.assets_directory()));
+    let mut asset_dirs = vec![assets_dir, project_root.join(&config.public_dir)];
+    asset_dirs.extend(collections.iter().map(|collection| collection.path.clone()));
+
+    // Sorted and deduplicated, since content collections may live inside the assets directory
+    let assets: BTreeMap<PathBuf, u64> = asset_dirs
+        .iter()
+        .flat_map(|dir| WalkBuilder::new(dir).git_ignore(false).build())
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+        .filter(|entry| is_media_file(entry.path()))
+        .map(|entry| {
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            (entry.into_path(), size)
+        })
+        .collect();
+
+    // Layouts and components reference assets too, so an asset whose file name appears in
+    // any source file outside the content collections counts as used
+    let sources = source_text(&config.src_dir_path(&project_root), &collections);
+
+    report.assets_checked = assets.len();
+    for (path, size) in assets {
+        let named_in_sources = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| sources.contains(name));
+        if !referenced.contains(&path) && !named_in_sources {
+            report.orphaned_size += size;
+            report.orphans.push(OrphanedAsset {
+                path: path.to_string_lossy().to_string(),
+                size,
+            });
+        }
+    }
+
+    info!(
+        "Astro Editor [ASSETS] Checked {} assets: {} orphaned ({} bytes), {} missing references",
+        report.assets_checked,
+        report.orphans.len(),
+        report.orphaned_size,
+        report.missing.len()
+    );
+    Ok(report)
+}
+
+/// Deletes the given assets. The project is checked again first, and any file that isn't
+/// an orphan any more (or never was) is kept. A file that can't be deleted doesn't stop
+/// the others; it's reported alongside the deleted paths.
+#[tauri::command]
+pub async fn delete_orphaned_assets(
+    project_path: String,
+    content_directory: Option<String>,
+    assets_directory: Option<String>,
+    paths: Vec<String>,
+) -> Result<AssetDeletionResult, String> {
+    let report = find_orphaned_assets(project_path, content_directory, assets_directory).await?;
+    let orphans: HashSet<PathBuf> = report
+        .orphans
+        .into_iter()
+        .map(|orphan| PathBuf::from(orphan.path))
+        .collect();
+
+    let mut result = AssetDeletionResult::default();
+    for path in paths {
+        let is_orphan = Path::new(&path)
+            .canonicalize()
+            .is_ok_and(|canonical| orphans.contains(&canonical));
+        if !is_orphan {
+            warn!("Astro Editor [ASSETS] Not deleting {path}: it is not an orphaned asset");
+            continue;
+        }
+
+        record_own_removal(Path::new(&path));
+        match std::fs::remove_file(&path) {
+            Ok(()) => result.deleted.push(path),
+            Err(e) => {
+                forget_own_changes(&[Path::new(&path)]);
+                warn!("Astro Editor [ASSETS] Failed to delete {path}: {e}");
+                result.failed.push(AssetDeletionFailure {
+                    path,
+                    error: format!("Failed to delete asset: {e}"),
+                });
+            }
+        }
+    }
+
+    info!(
+        "Astro Editor [ASSETS] Deleted {} orphaned assets ({} failed)",
+        result.deleted.len(),
+        result.failed.len()
+    );
+    Ok(result)
+}
+
+fn is_media_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_lowercase)
+        .is_some_and(|ext| {
+            IMAGE_EXTENSIONS.contains(&ext.as_str()) || MEDIA_EXTENSIONS.contains(&ext.as_str())
+        })
+}
+
+/// Whether a link target points at a file rather than a page
+fn is_file_reference(target: &str) -> bool {
+    let path = target.split(['?', '#']).next().unwrap_or(target);
+    Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            !ext.is_empty()
+                && ext.chars().all(|c| c.is_ascii_alphanumeric())
+                && !["md", "mdx", "html", "htm"].contains(&ext.to_lowercase().as_str())
+        })
+}
+
+fn collect_strings(value: &Value, strings: &mut Vec<String>) {
+    match value {
+        Value::String(s) => strings.push(s.clone()),
+        Value::Array(items) => items.iter().for_each(|item| collect_strings(item, strings)),
+        Value::Object(map) => map.values().for_each(|item| collect_strings(item, strings)),
+        _ => {}
+    }
+}
+
+/// The string values of a data file. JSON is parsed; YAML, TOML and anything else is
+/// split into quoted and unquoted values.
+fn data_file_strings(content: &str) -> Vec<String> {
+    let mut strings = Vec::new();
+    if let Ok(value) = serde_json::from_str::<Value>(content) {
+        collect_strings(&value, &mut strings);
+        return strings;
+    }
+
+    strings.extend(
+        asset_patterns()
+            .data_value
+            .captures_iter(content)
+            .filter_map(|cap| {
+                (1..=3).find_map(|group| cap.get(group).map(|m| m.as_str().trim().to_string()))
+            }),
+    );
+    strings
+}
+
+/// The contents of every source file under `src_dir`, outside the content collections
+fn source_text(src_dir: &Path, collections: &[Collection]) -> String {
+    let mut text = String::new();
+    for entry in WalkBuilder::new(src_dir)
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+    {
+        let path = entry.path();
+        let is_source = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext));
+        let in_content = collections
+            .iter()
+            .any(|collection| path.starts_with(&collection.path));
+        if is_source && !in_content {
+            if let Ok(content) = std::fs::read_to_string(path) {
+                text.push_str(&content);
+                text.push('\n');
+            }
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_asset_reference_styles() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{
+                // Astro's strict preset
+                "extends": "astro/tsconfigs/strict",
+                "compilerOptions": {
+                    "baseUrl": ".",
+                    "paths": { "@/*": ["src/*"], "@assets/*": ["src/assets/*"], },
+                },
+            }"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("astro.config.mjs"),
+            "export default defineConfig({ base: '/docs/' })",
+        )
+        .unwrap();
+
+        let asset = root.join("src/assets/blog/photo.png");
+        let document = root.join("src/content/blog/2024/post.md");
+        let reference =
+            |style, document: Option<&Path>| asset_reference(&root, &asset, style, document);
+
+        assert_eq!(
+            reference(AssetPathStyle::ProjectRoot, None).unwrap(),
+            "src/assets/blog/photo.png"
+        );
+        assert_eq!(
+            reference(AssetPathStyle::Relative, Some(&document)).unwrap(),
+            "../../../assets/blog/photo.png"
+        );
+        assert!(reference(AssetPathStyle::Relative, None).is_err());
+        assert_eq!(
+            reference(AssetPathStyle::Alias, None).unwrap(),
+            "@assets/blog/photo.png"
+        );
+        assert!(reference(AssetPathStyle::PublicUrl, None).is_err());
+
+        let public_asset = root.join("public/images/team photo.png");
+        assert_eq!(
+            asset_reference(&root, &public_asset, AssetPathStyle::PublicUrl, None).unwrap(),
+            "/docs/images/team%20photo.png"
+        );
+
+        let colocated = root.join("src/content/blog/2024/cover.png");
+        assert_eq!(
+            asset_reference(&root, &colocated, AssetPathStyle::Relative, Some(&document)).unwrap(),
+            "./cover.png"
+        );
+    }
+
+    #[test]
+    fn test_render_template() {
+        let tokens = TemplateTokens::new(
+            Path::new("/downloads/Team Photo.JPG"),
+            "0123456789abcdef",
+            "blog",
+            Some(Path::new("/site/src/content/blog/my-post/index.md")),
+        );
+
+        let render = |template| render_template(template, &tokens);
+        assert_eq!(render("{collection}/{slug}").unwrap(), "blog/my-post");
+        assert_eq!(render("{hash}.{ext}").unwrap(), "01234567.JPG");
+        assert_eq!(
+            render(DEFAULT_FILENAME_TEMPLATE).unwrap(),
+            format!("{}-team-photo.JPG", tokens.date)
+        );
+        assert!(render("../{name}").is_err());
+
+        let without_document = TemplateTokens {
+            slug: None,
+            ..tokens
+        };
+        assert!(render_template("{slug}.{ext}", &without_document).is_err());
+
+        assert!(validate_template("{slug}/{name}.{ext}", false).is_ok());
+        assert!(validate_template("{title}.{ext}", true)
+            .unwrap_err()
+            .contains("{title}"));
+        assert!(validate_template("{slug}/{name}", true).is_err());
+    }
+
+    #[test]
+    fn test_extract_and_resolve_references() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{ "compilerOptions": { "paths": { "@/*": ["src/*"], "@assets/*": ["src/assets/*"] } } }"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("public/images")).unwrap();
+        fs::write(root.join("public/images/logo.svg"), "<svg/>").unwrap();
+
+        let content = r#"import hero from '../../assets/hero.png'
+import { Image } from 'astro:assets'
+
+![A cat](./cat.png "Cat") and [the PDF](/files/guide%20v2.pdf#page=2)
+<Image src={hero} alt="" />
+<img src="@assets/blog/photo.jpg">
+[logo]: /images/logo.svg
+
+```md
+![ignored](./ignored.png)
+```
+"#;
+        let references = extract_body_references(content);
+        let summary: Vec<(&str, bool, Option<&str>, usize)> = references
+            .iter()
+            .map(|r| (r.target.as_str(), r.is_image, r.alt.as_deref(), r.line))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("../../assets/hero.png", false, None, 1),
+                ("./cat.png", true, Some("A cat"), 4),
+                ("/files/guide%20v2.pdf#page=2", false, None, 4),
+                ("{hero}", true, Some(""), 5),
+                ("@assets/blog/photo.jpg", true, None, 6),
+                ("/images/logo.svg", false, None, 7),
+            ]
+        );
+
+        let resolver = AssetResolver::new(root);
+        let document = root.join("src/content/blog/post.md");
+        let resolve = |target| resolver.resolve(target, Some(&document));
+        assert_eq!(
+            resolve("../../assets/hero.png"),
+            Some(root.join("src/assets/hero.png"))
+        );
+        assert_eq!(
+            resolve("@assets/blog/photo.jpg"),
+            Some(root.join("src/assets/blog/photo.jpg"))
+        );
+        assert_eq!(
+            resolve("/files/guide%20v2.pdf#page=2"),
+            Some(root.join("public/files/guide v2.pdf"))
+        );
+        assert_eq!(resolve("{hero}"), None);
+        assert_eq!(resolve("https://example.com/cat.png"), None);
+    }
+
+    #[tokio::test]
+    async fn test_find_and_delete_orphaned_assets() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+        let blog = root.join("src/content/blog");
+        let assets = root.join("src/assets/blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::create_dir_all(&assets).unwrap();
+        fs::create_dir_all(root.join("public/images")).unwrap();
+        fs::create_dir_all(root.join("src/layouts")).unwrap();
+
+        for path in [
+            assets.join("used.png"),
+            assets.join("cover.jpg"),
+            assets.join("old.png"),
+            root.join("public/images/stale.png"),
+            root.join("public/logo.svg"),
+        ] {
+            fs::write(path, "image").unwrap();
+        }
+        fs::write(root.join("public/robots.txt"), "User-agent: *").unwrap();
+        fs::write(
+            root.join("src/layouts/Base.astro"),
+            r#"<link rel="icon" href="/logo.svg" />"#,
+        )
+        .unwrap();
+        fs::write(
+            blog.join("post.md"),
+            "---\ntitle: Post\ncover:\n  src: ../../assets/blog/cover.jpg\n---\n\n![Used](../../assets/blog/used.png)\n\n![Gone](../../assets/blog/gone.png)\n\n[Another post](../other-post)\n",
+        )
+        .unwrap();
+
+        // Only referenced by data collection entries
+        fs::create_dir_all(root.join("src/content/authors")).unwrap();
+        fs::write(assets.join("avatar.png"), "image").unwrap();
+        fs::write(assets.join("john.png"), "image").unwrap();
+        fs::write(
+            root.join("src/content/authors/jane.json"),
+            r#"{ "name": "Jane", "avatar": "../../assets/blog/avatar.png" }"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/content/authors/john.yaml"),
+            "name: John\navatar: ../../assets/blog/john.png\n",
+        )
+        .unwrap();
+
+        let project_path = root.to_string_lossy().to_string();
+        let report = find_orphaned_assets(project_path.clone(), None, None)
+            .await
+            .unwrap();
+        assert_eq!(report.assets_checked, 7);
+        let orphans: Vec<&str> = report.orphans.iter().map(|o| o.path.as_str()).collect();
+        assert_eq!(
+            orphans,
+            vec![
+                root.join("public/images/stale.png").to_string_lossy(),
+                root.join("src/assets/blog/old.png").to_string_lossy(),
+            ]
+        );
+        assert_eq!(report.orphaned_size, 10);
+        assert_eq!(report.missing.len(), 1);
+        assert_eq!(report.missing[0].reference, "../../assets/blog/gone.png");
+        assert_eq!(report.missing[0].line, Some(9));
+
+        let result = delete_orphaned_assets(
+            project_path,
+            None,
+            None,
+            vec![
+                assets.join("old.png").to_string_lossy().to_string(),
+                assets.join("used.png").to_string_lossy().to_string(),
+            ],
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.deleted.len(), 1);
+        assert!(result.failed.is_empty());
+        assert!(!assets.join("old.png").exists());
+        assert!(assets.join("used.png").exists());
+    }
+}
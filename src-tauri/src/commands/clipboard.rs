@@ -1,8 +1,198 @@
+use crate::commands::assets::{AssetNaming, AssetPathStyle, ImportedAsset};
+use crate::commands::files::{import_asset, parse_frontmatter_internal};
+use crate::commands::images::{ImageOutputFormat, ImageProcessing};
+use image::codecs::png::PngEncoder;
+use image::RgbaImage;
+use pulldown_cmark::{html, Options, Parser};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
 use tauri_plugin_clipboard_manager::ClipboardExt;
 
+/// Fills in `{name}` in filename templates for pasted images
+const PASTED_IMAGE_NAME: &str = "pasted-image.png";
+
 #[tauri::command]
 pub async fn copy_text_to_clipboard(app: tauri::AppHandle, text: String) -> Result<(), String> {
     app.clipboard()
         .write_text(text)
         .map_err(|e| format!("Failed to copy to clipboard: {e}"))
 }
+
+/// Renders Markdown (a selection or a whole document) to HTML and puts it on the clipboard
+/// alongside the Markdown as plain text, so it pastes formatted into email or Google Docs.
+/// The document's frontmatter is left out unless `include_frontmatter` is set.
+#[tauri::command]
+pub async fn copy_as_html(
+    app: tauri::AppHandle,
+    markdown: String,
+    include_frontmatter: Option<bool>,
+) -> Result<(), String> {
+    let (html, text) = render_clipboard_html(&markdown, include_frontmatter.unwrap_or(false));
+    app.clipboard()
+        .write_html(html, Some(text))
+        .map_err(|e| format!("Failed to copy to clipboard: {e}"))
+}
+
+/// Saves the image on the clipboard (a screenshot, or an image copied in another app) to
+/// the assets directory, exactly like an imported file, and returns the path to write into
+/// the document. Images are stored as PNG unless `format` or `processing` converts them.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn paste_image_from_clipboard(
+    app: tauri::AppHandle,
+    project_path: String,
+    collection: String,
+    assets_directory: Option<String>,
+    target_document: Option<String>,
+    path_style: Option<AssetPathStyle>,
+    naming: Option<AssetNaming>,
+    processing: Option<ImageProcessing>,
+    format: Option<ImageOutputFormat>,
+) -> Result<ImportedAsset, String> {
+    let (rgba, width, height) = {
+        let image = app
+            .clipboard()
+            .read_image()
+            .map_err(|e| format!("No image on the clipboard: {e}"))?;
+        (image.rgba().to_vec(), image.width(), image.height())
+    };
+
+    let content = tokio::task::spawn_blocking(move || encode_png(rgba, width, height))
+        .await
+        .map_err(|e| format!("Failed to encode image: {e}"))??;
+
+    let processing = match format {
+        Some(format) => Some(ImageProcessing {
+            convert_to: Some(format),
+            ..processing.unwrap_or_default()
+        }),
+        None => processing,
+    };
+
+    import_asset(
+        Path::new(PASTED_IMAGE_NAME),
+        content,
+        &project_path,
+        &collection,
+        assets_directory,
+        target_document,
+        path_style,
+        naming,
+        processing,
+    )
+    .await
+}
+
+fn encode_png(rgba: Vec<u8>, width: u32, height: u32) -> Result<Vec<u8>, String> {
+    let image = RgbaImage::from_raw(width, height, rgba)
+        .ok_or_else(|| "Clipboard image has an invalid size".to_string())?;
+    let mut png = Vec::new();
+    image
+        .write_with_encoder(PngEncoder::new(&mut png))
+        .map_err(|e| format!("Failed to encode image: {e}"))?;
+    Ok(png)
+}
+
+/// Returns the HTML and plain text flavours of `markdown`. MDX imports and exports are
+/// dropped; frontmatter becomes a table above the content.
+fn render_clipboard_html(markdown: &str, include_frontmatter: bool) -> (String, String) {
+    // Text that can't be parsed as a document, like a selection starting with a `---`
+    // rule, is rendered as written
+    let parsed = parse_frontmatter_internal(markdown).ok();
+    let body = parsed
+        .as_ref()
+        .map_or(markdown, |parsed| parsed.content.as_str())
+        .trim();
+
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_FOOTNOTES);
+
+    let mut html = String::new();
+    let mut text = String::new();
+    if let Some(parsed) = parsed
+        .as_ref()
+        .filter(|parsed| include_frontmatter && !parsed.frontmatter.is_empty())
+    {
+        html.push_str(&frontmatter_table(
+            &parsed.raw_frontmatter,
+            &parsed.frontmatter,
+        ));
+        text.push_str(&format!("---\n{}\n---\n\n", parsed.raw_frontmatter));
+    }
+    html::push_html(&mut html, Parser::new_ext(body, options));
+    text.push_str(body);
+
+    (html, text)
+}
+
+/// Frontmatter fields in the order they're written
+fn frontmatter_table(raw_frontmatter: &str, frontmatter: &HashMap<String, Value>) -> String {
+    let mut fields: Vec<(&String, &Value)> = frontmatter.iter().collect();
+    fields.sort_by_key(|(key, _)| {
+        raw_frontmatter
+            .lines()
+            .position(|line| line.starts_with(&format!("{key}:")))
+    });
+
+    let mut table = String::from("<table>\n");
+    for (key, value) in fields {
+        let value = match value {
+            Value::String(s) => s.clone(),
+            Value::Array(items) => items
+                .iter()
+                .map(|item| {
+                    item.as_str()
+                        .map_or_else(|| item.to_string(), str::to_string)
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+            other => other.to_string(),
+        };
+        table.push_str(&format!(
+            "<tr><th>{}</th><td>{}</td></tr>\n",
+            escape_html(key),
+            escape_html(&value)
+        ));
+    }
+    table.push_str("</table>\n");
+    table
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_clipboard_html() {
+        let document = "---\ntitle: Hello <World>\ntags:\n  - one\n  - two\ndraft: false\n---\n\nimport Callout from '../../components/Callout.astro';\n\n# Heading\n\nSome **bold** text and ~~old~~ news.\n";
+
+        let (html, text) = render_clipboard_html(document, false);
+        assert_eq!(
+            html,
+            "<h1>Heading</h1>\n<p>Some <strong>bold</strong> text and <del>old</del> news.</p>\n"
+        );
+        assert_eq!(text, "# Heading\n\nSome **bold** text and ~~old~~ news.");
+
+        let (html, text) = render_clipboard_html(document, true);
+        assert!(html.starts_with(
+            "<table>\n<tr><th>title</th><td>Hello &lt;World&gt;</td></tr>\n<tr><th>tags</th><td>one, two</td></tr>\n<tr><th>draft</th><td>false</td></tr>\n</table>\n<h1>"
+        ));
+        assert!(text.starts_with("---\ntitle: Hello <World>\n"));
+
+        // A selection is rendered as it is
+        let (html, _) = render_clipboard_html("- [x] done\n\n---\n\nafter", true);
+        assert!(html.contains("checkbox"));
+        assert!(html.contains("<hr />"));
+    }
+}
@@ -1,4 +1,16 @@
+use crate::astro_config::load_astro_project_config;
+use crate::commands::assets::{
+    asset_reference, find_duplicate_asset, index_asset, render_template, unique_asset_path,
+    AssetNaming, AssetPathStyle, ImportedAsset, TemplateTokens, DEFAULT_FILENAME_TEMPLATE,
+    DEFAULT_FOLDER_TEMPLATE,
+};
+use crate::commands::images::{process_image, ImageProcessing};
+use crate::commands::links::{collect_link_rewrites, LinkRewriteResult, LinkRewrites};
+use crate::commands::watcher::{
+    content_hash, forget_own_changes, record_own_removal, record_own_rename, write_tracked,
+};
 use chrono::Local;
+use log::info;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -53,7 +65,7 @@ pub async fn write_file(
     project_root: String,
 ) -> Result<(), String> {
     let validated_path = validate_project_path(&file_path, &project_root)?;
-    std::fs::write(&validated_path, content).map_err(|e| format!("Failed to write file: {e}"))
+    write_tracked(&validated_path, content).map_err(|e| format!("Failed to write file: {e}"))
 }
 
 #[tauri::command]
@@ -75,7 +87,7 @@ pub async fn create_file(
         return Err("File already exists".to_string());
     }
 
-    std::fs::write(&validated_final_path, content)
+    write_tracked(&validated_final_path, content)
         .map_err(|e| format!("Failed to create file: {e}"))?;
 
     Ok(validated_final_path.to_string_lossy().to_string())
@@ -84,6 +96,7 @@ pub async fn create_file(
 #[tauri::command]
 pub async fn delete_file(file_path: String, project_root: String) -> Result<(), String> {
     let validated_path = validate_project_path(&file_path, &project_root)?;
+    record_own_removal(&validated_path);
     std::fs::remove_file(&validated_path).map_err(|e| format!("Failed to delete file: {e}"))
 }
 
@@ -92,15 +105,40 @@ pub async fn rename_file(
     old_path: String,
     new_path: String,
     project_root: String,
-) -> Result<(), String> {
+    update_links: Option<bool>,
+    content_directory: Option<String>,
+) -> Result<LinkRewriteResult, String> {
     let validated_old_path = validate_project_path(&old_path, &project_root)?;
     let validated_new_path = validate_project_path(&new_path, &project_root)?;
-    std::fs::rename(&validated_old_path, &validated_new_path)
-        .map_err(|e| format!("Failed to rename file: {e}"))
+
+    // Links are collected first, while they still resolve to the old path, but only
+    // written once the rename has succeeded
+    let rewrites = if update_links.unwrap_or(false) {
+        Some(
+            collect_link_rewrites(
+                &project_root,
+                content_directory,
+                Path::new(&old_path),
+                Path::new(&new_path),
+            )
+            .await?,
+        )
+    } else {
+        None
+    };
+
+    record_own_rename(&validated_old_path, &validated_new_path);
+    if let Err(e) = std::fs::rename(&validated_old_path, &validated_new_path) {
+        forget_own_changes(&[&validated_old_path, &validated_new_path]);
+        return Err(format!("Failed to rename file: {e}"));
+    }
+
+    // The file has moved, so the rename succeeded even if some links couldn't be updated
+    Ok(rewrites.map(LinkRewrites::apply).unwrap_or_default())
 }
 
 /// Convert a string to kebab case
-fn to_kebab_case(s: &str) -> String {
+pub(crate) fn to_kebab_case(s: &str) -> String {
     let parts: Vec<&str> = s.split('.').collect();
     let extension = if parts.len() > 1 { parts.last() } else { None };
 
@@ -135,92 +173,187 @@ pub async fn copy_file_to_assets(
     source_path: String,
     project_path: String,
     collection: String,
-) -> Result<String, String> {
-    copy_file_to_assets_with_override(source_path, project_path, collection, None).await
+) -> Result<ImportedAsset, String> {
+    copy_file_to_assets_with_override(
+        source_path,
+        project_path,
+        collection,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
 }
 
+/// Copies a file into the assets directory and returns the path to write into
+/// `target_document`, in `path_style` (project-relative when not given). `naming` sets the
+/// destination folder and filename templates. Public URLs need the file in `public/`, so
+/// without an assets override that style copies to `public/assets`.
+///
+/// Images are run through `processing` first. A file whose processed content is already in
+/// the assets directory (or, for co-located assets, next to the document) isn't copied
+/// again; the existing file's path is returned.
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn copy_file_to_assets_with_override(
     source_path: String,
     project_path: String,
     collection: String,
     assets_directory: Option<String>,
-) -> Result<String, String> {
+    target_document: Option<String>,
+    path_style: Option<AssetPathStyle>,
+    naming: Option<AssetNaming>,
+    processing: Option<ImageProcessing>,
+) -> Result<ImportedAsset, String> {
+    let source = PathBuf::from(&source_path);
+    source.file_name().ok_or("Invalid source file path")?;
+    let content = std::fs::read(&source).map_err(|e| format!("Failed to read file: {e}"))?;
+
+    import_asset(
+        &source,
+        content,
+        &project_path,
+        &collection,
+        assets_directory,
+        target_document,
+        path_style,
+        naming,
+        processing,
+    )
+    .await
+}
+
+/// Stores `content` in the assets directory as if it had been copied from `source`, whose
+/// name and extension fill in the filename template. Shared by file imports and pastes.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn import_asset(
+    source: &Path,
+    mut content: Vec<u8>,
+    project_path: &str,
+    collection: &str,
+    assets_directory: Option<String>,
+    target_document: Option<String>,
+    path_style: Option<AssetPathStyle>,
+    naming: Option<AssetNaming>,
+    processing: Option<ImageProcessing>,
+) -> Result<ImportedAsset, String> {
     use std::fs;
 
     // Validate project path
     let validated_project_root = Path::new(&project_path)
         .canonicalize()
         .map_err(|_| "Invalid project root".to_string())?;
+    let path_style = path_style.unwrap_or_default();
+    let validated_document = target_document
+        .map(|document| validate_project_path(&document, project_path))
+        .transpose()?;
 
-    // Create the assets directory structure (use override if provided)
+    // Use the assets directory override if provided
     let assets_base = if let Some(assets_override) = assets_directory {
         validated_project_root.join(assets_override)
+    } else if path_style == AssetPathStyle::PublicUrl {
+        validated_project_root
+            .join(load_astro_project_config(&validated_project_root).public_dir)
+            .join("assets")
     } else {
-        validated_project_root.join("src").join("assets")
+        validated_project_root
+            .join(load_astro_project_config(&validated_project_root).assets_directory())
     };
 
-    let assets_dir = assets_base.join(&collection);
+    let mut converted_extension = None;
+    if let Some(processing) = processing {
+        let original = content.clone();
+        let processed = tokio::task::spawn_blocking(move || process_image(&original, &processing))
+            .await
+            .map_err(|e| format!("Failed to process image: {e}"))??;
+        if let Some(processed) = processed {
+            content = processed.content;
+            converted_extension = processed.extension;
+        }
+    }
 
-    fs::create_dir_all(&assets_dir)
-        .map_err(|e| format!("Failed to create assets directory: {e}"))?;
+    let hash = content_hash(&content);
+    let mut tokens = TemplateTokens::new(source, &hash, collection, validated_document.as_deref());
+    if let Some(extension) = converted_extension {
+        tokens.ext = extension.to_string();
+    }
 
-    // Get the source file info
-    let source = PathBuf::from(&source_path);
-    let file_name = source
-        .file_name()
-        .ok_or("Invalid source file path")?
-        .to_string_lossy();
-
-    // Extract extension
-    let extension = source
-        .extension()
-        .and_then(|ext| ext.to_str())
-        .unwrap_or("");
+    let naming = naming.unwrap_or_default();
+    let folder = render_template(
+        naming
+            .folder_template
+            .as_deref()
+            .unwrap_or(DEFAULT_FOLDER_TEMPLATE),
+        &tokens,
+    )?;
+    let file_name = render_template(
+        naming
+            .filename_template
+            .as_deref()
+            .unwrap_or(DEFAULT_FILENAME_TEMPLATE),
+        &tokens,
+    )?;
 
-    // Create the base filename with date prefix
-    let date_prefix = Local::now().format("%Y-%m-%d").to_string();
-    let name_without_ext = file_name.trim_end_matches(&format!(".{extension}"));
-    let kebab_name = to_kebab_case(name_without_ext);
+    // `./` folders are next to the document
+    let (assets_dir, duplicates_dir) = if folder == "." || folder.starts_with("./") {
+        let assets_dir = validated_document
+            .as_deref()
+            .and_then(Path::parent)
+            .ok_or("Co-located assets need a target document")?
+            .join(&folder);
+        (assets_dir.clone(), assets_dir)
+    } else {
+        (assets_base.join(&folder), assets_base)
+    };
 
-    // Build the new filename
-    let mut base_name = format!("{date_prefix}-{kebab_name}");
-    if !extension.is_empty() {
-        base_name.push('.');
-        base_name.push_str(extension);
+    // Reuse an identical file the document can reference, rather than adding a copy
+    if let Some(existing) = find_duplicate_asset(&duplicates_dir, content.len() as u64, &hash) {
+        if let Ok(reference) = asset_reference(
+            &validated_project_root,
+            &existing,
+            path_style,
+            validated_document.as_deref(),
+        ) {
+            info!(
+                "Astro Editor [ASSETS] {} is identical to {}, not copying",
+                source.display(),
+                existing.display()
+            );
+            return Ok(ImportedAsset {
+                path: reference,
+                deduplicated: true,
+            });
+        }
     }
 
-    // Handle conflicts by appending -1, -2, etc.
-    let mut final_path = assets_dir.join(&base_name);
-    let mut counter = 1;
+    fs::create_dir_all(&assets_dir)
+        .map_err(|e| format!("Failed to create assets directory: {e}"))?;
 
-    while final_path.exists() {
-        let name_with_counter = if extension.is_empty() {
-            format!("{date_prefix}-{kebab_name}-{counter}")
-        } else {
-            format!("{date_prefix}-{kebab_name}-{counter}.{extension}")
-        };
-        final_path = assets_dir.join(name_with_counter);
-        counter += 1;
-    }
+    // Handle conflicts by appending -1, -2, etc.
+    let final_path = unique_asset_path(&assets_dir, &file_name, &tokens.ext);
 
     // Validate the final destination is within project bounds
     let final_path_str = final_path.to_string_lossy().to_string();
-    let validated_final_path = validate_project_path(&final_path_str, &project_path)?;
+    let validated_final_path = validate_project_path(&final_path_str, project_path)?;
+
+    // Work out the reference first, so a style that can't be used leaves no stray copy
+    let reference = asset_reference(
+        &validated_project_root,
+        &validated_final_path,
+        path_style,
+        validated_document.as_deref(),
+    )?;
 
     // Copy the file
-    fs::copy(&source_path, &validated_final_path)
-        .map_err(|e| format!("Failed to copy file: {e}"))?;
-
-    // Return the relative path from the project root (for markdown)
-    let relative_path = validated_final_path
-        .strip_prefix(&validated_project_root)
-        .map_err(|_| "Failed to create relative path")?
-        .to_string_lossy()
-        .to_string();
+    fs::write(&validated_final_path, content).map_err(|e| format!("Failed to copy file: {e}"))?;
+    index_asset(&validated_final_path, &hash);
 
-    // Convert to forward slashes for markdown compatibility
-    Ok(relative_path.replace('\\', "/"))
+    Ok(ImportedAsset {
+        path: reference,
+        deduplicated: false,
+    })
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -256,7 +389,7 @@ pub async fn update_frontmatter(
     let parsed = parse_frontmatter(&content)?;
     let new_content = rebuild_markdown_with_frontmatter(&frontmatter, &parsed.content)?;
 
-    std::fs::write(&validated_path, new_content).map_err(|e| format!("Failed to write file: {e}"))
+    write_tracked(&validated_path, new_content).map_err(|e| format!("Failed to write file: {e}"))
 }
 
 #[tauri::command]
@@ -275,7 +408,116 @@ pub async fn save_markdown_content(
         &content,
         schema_field_order,
     )?;
-    std::fs::write(&validated_path, new_content).map_err(|e| format!("Failed to write file: {e}"))
+    write_tracked(&validated_path, new_content).map_err(|e| format!("Failed to write file: {e}"))
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct BulkFrontmatterResult {
+    pub updated: Vec<String>,
+    pub unchanged: Vec<String>,
+    pub failed: Vec<BulkFrontmatterFailure>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct BulkFrontmatterFailure {
+    pub path: String,
+    pub error: String,
+}
+
+/// Applies a frontmatter transformation to many files at once
+///
+/// The closure receives each file's frontmatter and returns whether it changed it.
+/// Unchanged files are never rewritten. Imports and body content are preserved, and
+/// the original key order is kept (renamed keys take the place of the key they replace).
+pub fn update_frontmatter_bulk<F>(
+    file_paths: &[String],
+    project_root: &str,
+    mut transform: F,
+) -> BulkFrontmatterResult
+where
+    F: FnMut(&mut HashMap<String, Value>) -> bool,
+{
+    let mut result = BulkFrontmatterResult::default();
+
+    for file_path in file_paths {
+        match update_frontmatter_in_file(file_path, project_root, &mut transform) {
+            Ok(true) => result.updated.push(file_path.clone()),
+            Ok(false) => result.unchanged.push(file_path.clone()),
+            Err(error) => result.failed.push(BulkFrontmatterFailure {
+                path: file_path.clone(),
+                error,
+            }),
+        }
+    }
+
+    result
+}
+
+fn update_frontmatter_in_file<F>(
+    file_path: &str,
+    project_root: &str,
+    transform: &mut F,
+) -> Result<bool, String>
+where
+    F: FnMut(&mut HashMap<String, Value>) -> bool,
+{
+    let validated_path = validate_project_path(file_path, project_root)?;
+    let content = std::fs::read_to_string(&validated_path)
+        .map_err(|e| format!("Failed to read file: {e}"))?;
+
+    let parsed = parse_frontmatter(&content)?;
+    let original_keys = frontmatter_key_order(&parsed.raw_frontmatter);
+    let original = parsed.frontmatter.clone();
+    let mut frontmatter = parsed.frontmatter;
+
+    if !transform(&mut frontmatter) {
+        return Ok(false);
+    }
+
+    // Keep existing keys where they were. A new key holding the exact value of a removed
+    // key is a rename, so it takes that key's place; anything else goes at the end.
+    let mut new_keys: Vec<&String> = frontmatter
+        .keys()
+        .filter(|key| !original_keys.contains(key))
+        .collect();
+    new_keys.sort();
+
+    let mut key_order = Vec::new();
+    for key in &original_keys {
+        if frontmatter.contains_key(key) {
+            key_order.push(key.clone());
+        } else if let Some(position) = new_keys
+            .iter()
+            .position(|new_key| frontmatter.get(*new_key) == original.get(key))
+        {
+            key_order.push(new_keys.remove(position).clone());
+        }
+    }
+    key_order.extend(new_keys.into_iter().cloned());
+
+    let new_content = rebuild_markdown_with_frontmatter_and_imports_ordered(
+        &frontmatter,
+        &parsed.imports,
+        &parsed.content,
+        Some(key_order),
+    )?;
+
+    write_tracked(&validated_path, new_content)
+        .map_err(|e| format!("Failed to write file: {e}"))?;
+
+    Ok(true)
+}
+
+/// Returns the top-level keys of a raw YAML frontmatter block in document order
+fn frontmatter_key_order(raw_frontmatter: &str) -> Vec<String> {
+    raw_frontmatter
+        .lines()
+        .filter(|line| !line.starts_with(' ') && !line.starts_with('\t'))
+        .filter(|line| !line.trim_start().starts_with('#') && !line.starts_with('-'))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, _)| key.trim().to_string())
+        .filter(|key| !key.is_empty())
+        .collect()
 }
 
 pub fn parse_frontmatter_internal(content: &str) -> Result<MarkdownContent, String> {
@@ -761,10 +1003,9 @@ fn validate_app_data_path(file_path: &str, app_data_dir: &str) -> Result<PathBuf
                         parent.display()
                     );
                     if let Err(e) = std::fs::create_dir_all(parent) {
-                        return Err(std::io::Error::new(
-                            std::io::ErrorKind::Other,
-                            format!("Failed to create parent directory: {e}"),
-                        ));
+                        return Err(std::io::Error::other(format!(
+                            "Failed to create parent directory: {e}"
+                        )));
                     }
                 }
                 parent.canonicalize().map(|p| p.join(filename))
@@ -852,7 +1093,7 @@ pub async fn write_file_content(
             .map_err(|e| format!("Failed to create directories: {e}"))?;
     }
 
-    std::fs::write(&validated_path, content).map_err(|e| format!("Failed to write file: {e}"))
+    write_tracked(&validated_path, content).map_err(|e| format!("Failed to write file: {e}"))
 }
 
 #[tauri::command]
@@ -1532,7 +1773,7 @@ Regular markdown content here."#;
         .await;
 
         assert!(result.is_ok(), "Failed with error: {:?}", result.err());
-        let relative_path = result.unwrap();
+        let relative_path = result.unwrap().path;
 
         // Check the returned path format
         assert!(relative_path.starts_with("src/assets/blog/"));
@@ -1578,7 +1819,7 @@ Regular markdown content here."#;
         .await;
 
         assert!(result.is_ok(), "Failed with error: {:?}", result.err());
-        let relative_path = result.unwrap();
+        let relative_path = result.unwrap().path;
 
         // Should have -1 suffix
         assert!(relative_path.contains(&format!("{date_prefix}-test-file-1.md")));
@@ -1589,6 +1830,146 @@ Regular markdown content here."#;
         assert!(new_file.exists());
     }
 
+    #[tokio::test]
+    async fn test_copy_file_to_assets_relative_to_document() {
+        use std::fs;
+        use tempfile::TempDir;
+
+        let source_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        let document = project_dir.path().join("src/content/blog/post.md");
+        fs::create_dir_all(document.parent().unwrap()).unwrap();
+        fs::write(&document, "# Post").unwrap();
+
+        let test_file_path = source_dir.path().join("photo.png");
+        fs::write(&test_file_path, b"fake image data").unwrap();
+
+        let result = copy_file_to_assets_with_override(
+            test_file_path.to_str().unwrap().to_string(),
+            project_dir.path().to_str().unwrap().to_string(),
+            "blog".to_string(),
+            None,
+            Some(document.to_str().unwrap().to_string()),
+            Some(AssetPathStyle::Relative),
+            None,
+            None,
+        )
+        .await;
+
+        assert!(result.is_ok(), "Failed with error: {:?}", result.err());
+        let relative_path = result.unwrap().path;
+        assert!(relative_path.starts_with("../../assets/blog/"));
+        assert!(document.parent().unwrap().join(&relative_path).exists());
+
+        // Without a tsconfig.json alias the style fails, and no copy is left behind
+        let alias = copy_file_to_assets_with_override(
+            test_file_path.to_str().unwrap().to_string(),
+            project_dir.path().to_str().unwrap().to_string(),
+            "blog".to_string(),
+            None,
+            None,
+            Some(AssetPathStyle::Alias),
+            None,
+            None,
+        )
+        .await;
+        assert!(alias.is_err());
+        let copies = fs::read_dir(project_dir.path().join("src/assets/blog")).unwrap();
+        assert_eq!(copies.count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_copy_file_to_assets_with_naming_templates() {
+        use std::fs;
+        use tempfile::TempDir;
+
+        let source_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        let document = project_dir.path().join("src/content/blog/my-post/index.md");
+        fs::create_dir_all(document.parent().unwrap()).unwrap();
+        fs::write(&document, "# Post").unwrap();
+
+        let test_file_path = source_dir.path().join("Cover Image.jpg");
+        fs::write(&test_file_path, b"fake image data").unwrap();
+
+        let copy = |naming: AssetNaming| {
+            copy_file_to_assets_with_override(
+                test_file_path.to_str().unwrap().to_string(),
+                project_dir.path().to_str().unwrap().to_string(),
+                "blog".to_string(),
+                None,
+                Some(document.to_str().unwrap().to_string()),
+                Some(AssetPathStyle::Relative),
+                Some(naming),
+                None,
+            )
+        };
+
+        // Co-located next to the post, with the existing conflict counter
+        let colocated = AssetNaming {
+            folder_template: Some("./".to_string()),
+            filename_template: Some("{name}.{ext}".to_string()),
+        };
+        assert_eq!(
+            copy(colocated.clone()).await.unwrap().path,
+            "./cover-image.jpg"
+        );
+        fs::write(&test_file_path, b"edited image data").unwrap();
+        assert_eq!(copy(colocated).await.unwrap().path, "./cover-image-1.jpg");
+
+        let hashed = copy(AssetNaming {
+            folder_template: Some("{collection}/{slug}".to_string()),
+            filename_template: Some("{hash}.{ext}".to_string()),
+        })
+        .await
+        .unwrap()
+        .path;
+        assert!(hashed.starts_with("../../../assets/blog/my-post/"));
+        assert_eq!(hashed.len(), "../../../assets/blog/my-post/".len() + 12);
+
+        let escaping = copy(AssetNaming {
+            folder_template: Some("../../{name}".to_string()),
+            filename_template: None,
+        })
+        .await;
+        assert!(escaping.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_copy_file_to_assets_deduplicates() {
+        use std::fs;
+        use tempfile::TempDir;
+
+        let source_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        let screenshot = source_dir.path().join("Screenshot.png");
+        fs::write(&screenshot, b"screenshot").unwrap();
+        let renamed = source_dir.path().join("same-screenshot.png");
+        fs::write(&renamed, b"screenshot").unwrap();
+
+        let copy = |source: &std::path::Path, collection: &str| {
+            copy_file_to_assets(
+                source.to_str().unwrap().to_string(),
+                project_dir.path().to_str().unwrap().to_string(),
+                collection.to_string(),
+            )
+        };
+
+        let first = copy(&screenshot, "blog").await.unwrap();
+        assert!(!first.deduplicated);
+
+        // Same content under another name, dropped into another collection
+        let second = copy(&renamed, "notes").await.unwrap();
+        assert!(second.deduplicated);
+        assert_eq!(second.path, first.path);
+        assert!(!project_dir.path().join("src/assets/notes").exists());
+
+        fs::write(&renamed, b"edited screenshot").unwrap();
+        let edited = copy(&renamed, "notes").await.unwrap();
+        assert!(!edited.deduplicated);
+        assert_ne!(edited.path, first.path);
+    }
+
     #[tokio::test]
     async fn test_copy_file_to_assets_creates_directory() {
         use std::fs;
@@ -1620,7 +2001,7 @@ Regular markdown content here."#;
         assert!(assets_dir.exists());
 
         // File should be copied
-        let relative_path = result.unwrap();
+        let relative_path = result.unwrap().path;
         let dest_path = project_dir.path().join(&relative_path);
         assert!(dest_path.exists());
     }
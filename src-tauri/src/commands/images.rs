@@ -0,0 +1,702 @@
+use crate::commands::assets::{extract_body_references, AssetResolver};
+use crate::commands::project::{scan_collection_files, scan_project_with_content_dir};
+use image::codecs::avif::AvifEncoder;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader};
+use log::info;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_QUALITY: u8 = 80;
+
+/// Image files the audit looks at, including ones the editor can't decode
+pub const IMAGE_EXTENSIONS: [&str; 11] = [
+    "png", "jpg", "jpeg", "gif", "webp", "avif", "svg", "bmp", "ico", "tif", "tiff",
+];
+
+/// The image audit reports files larger than this
+const DEFAULT_MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Wider than any layout needs, even on high-density screens
+const DEFAULT_MAX_WIDTH: u32 = 2560;
+
+/// rav1e speed, 1 (slowest, smallest) to 10. Imports should take seconds, not minutes.
+const AVIF_SPEED: u8 = 7;
+
+/// Processing applied to images as they are imported. Unset fields leave images as they are.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageProcessing {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_width: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_height: Option<u32>,
+    /// Removes EXIF (including GPS location), XMP and text metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strip_metadata: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub convert_to: Option<ImageOutputFormat>,
+    /// 1-100, for JPEG and AVIF output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality: Option<u8>,
+}
+
+/// Formats images can be converted to. WebP output is lossless, as there is no pure-Rust
+/// lossy WebP encoder; AVIF gives the smallest files.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageOutputFormat {
+    Webp,
+    Avif,
+}
+
+impl ImageOutputFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ImageOutputFormat::Webp => "webp",
+            ImageOutputFormat::Avif => "avif",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessedImage {
+    pub content: Vec<u8>,
+    pub extension: Option<&'static str>, // Set when the image was converted
+}
+
+/// Applies `options` to an image. Only JPEG, PNG and WebP are processed (GIFs may be
+/// animated and SVGs aren't raster images); `None` means the file is left as it is.
+///
+/// Resizing and conversion re-encode the image, which always drops its metadata and
+/// applies the EXIF orientation to the pixels. Stripping metadata on its own is lossless,
+/// unless the image has to be rotated to keep its orientation.
+pub fn process_image(
+    content: &[u8],
+    options: &ImageProcessing,
+) -> Result<Option<ProcessedImage>, String> {
+    let format = match image::guess_format(content) {
+        Ok(format @ (ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP)) => format,
+        _ => return Ok(None),
+    };
+
+    let mut decoder = ImageReader::with_format(Cursor::new(content), format)
+        .into_decoder()
+        .map_err(|e| format!("Failed to read image: {e}"))?;
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let (width, height) = match orientation {
+        Orientation::Rotate90
+        | Orientation::Rotate270
+        | Orientation::Rotate90FlipH
+        | Orientation::Rotate270FlipH => {
+            let (width, height) = decoder.dimensions();
+            (height, width)
+        }
+        _ => decoder.dimensions(),
+    };
+
+    let max_width = options.max_width.unwrap_or(u32::MAX);
+    let max_height = options.max_height.unwrap_or(u32::MAX);
+    let resize = width > max_width || height > max_height;
+    let strip = options.strip_metadata.unwrap_or(false);
+    let rotate = strip && orientation != Orientation::NoTransforms;
+
+    if !resize && !rotate && options.convert_to.is_none() {
+        let stripped = strip.then(|| strip_metadata(content, format)).flatten();
+        return Ok(stripped.map(|content| ProcessedImage {
+            content,
+            extension: None,
+        }));
+    }
+
+    let mut image =
+        DynamicImage::from_decoder(decoder).map_err(|e| format!("Failed to read image: {e}"))?;
+    image.apply_orientation(orientation);
+    if resize {
+        image = image.resize(max_width, max_height, FilterType::Lanczos3);
+    }
+
+    let quality = options.quality.unwrap_or(DEFAULT_QUALITY);
+    let mut output = Vec::new();
+    let result = match (options.convert_to, format) {
+        (Some(ImageOutputFormat::Avif), _) => without_extra_channels(image).write_with_encoder(
+            AvifEncoder::new_with_speed_quality(&mut output, AVIF_SPEED, quality),
+        ),
+        (Some(ImageOutputFormat::Webp), _) | (None, ImageFormat::WebP) => {
+            without_extra_channels(image).write_with_encoder(WebPEncoder::new_lossless(&mut output))
+        }
+        (None, ImageFormat::Png) => image.write_with_encoder(PngEncoder::new(&mut output)),
+        (None, _) => DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut output, quality)),
+    };
+    result.map_err(|e| format!("Failed to encode image: {e}"))?;
+
+    Ok(Some(ProcessedImage {
+        content: output,
+        extension: options.convert_to.map(ImageOutputFormat::extension),
+    }))
+}
+
+/// WebP and AVIF encoders only take 8-bit RGB(A)
+fn without_extra_channels(image: DynamicImage) -> DynamicImage {
+    if image.color().has_alpha() {
+        DynamicImage::ImageRgba8(image.to_rgba8())
+    } else {
+        DynamicImage::ImageRgb8(image.to_rgb8())
+    }
+}
+
+/// Removes metadata without re-encoding. Returns `None` if there was nothing to remove or
+/// the file couldn't be parsed.
+fn strip_metadata(content: &[u8], format: ImageFormat) -> Option<Vec<u8>> {
+    let stripped = match format {
+        ImageFormat::Jpeg => strip_jpeg_metadata(content)?,
+        ImageFormat::Png => strip_png_metadata(content)?,
+        ImageFormat::WebP => strip_webp_metadata(content)?,
+        _ => return None,
+    };
+    (stripped.len() != content.len()).then_some(stripped)
+}
+
+/// Drops APP1 (EXIF and XMP) and APP13 (IPTC) segments. Everything from the start of the
+/// scan on is image data and copied as is.
+fn strip_jpeg_metadata(content: &[u8]) -> Option<Vec<u8>> {
+    const SOI: [u8; 2] = [0xFF, 0xD8];
+    const APP1: u8 = 0xE1;
+    const APP13: u8 = 0xED;
+    const SOS: u8 = 0xDA;
+
+    if !content.starts_with(&SOI) {
+        return None;
+    }
+
+    let mut output = SOI.to_vec();
+    let mut pos = 2;
+    while pos + 4 <= content.len() {
+        if content[pos] != 0xFF {
+            return None;
+        }
+        let marker = content[pos + 1];
+        if marker == SOS {
+            break;
+        }
+
+        let length = u16::from_be_bytes([content[pos + 2], content[pos + 3]]) as usize;
+        let end = pos + 2 + length;
+        if length < 2 || end > content.len() {
+            return None;
+        }
+        if marker != APP1 && marker != APP13 {
+            output.extend_from_slice(&content[pos..end]);
+        }
+        pos = end;
+    }
+
+    output.extend_from_slice(content.get(pos..)?);
+    Some(output)
+}
+
+/// Drops `eXIf`, text and timestamp chunks
+fn strip_png_metadata(content: &[u8]) -> Option<Vec<u8>> {
+    const SIGNATURE_LEN: usize = 8;
+    const METADATA_CHUNKS: [&[u8; 4]; 5] = [b"eXIf", b"tEXt", b"zTXt", b"iTXt", b"tIME"];
+
+    let mut output = content.get(..SIGNATURE_LEN)?.to_vec();
+    let mut pos = SIGNATURE_LEN;
+    while pos < content.len() {
+        let length = u32::from_be_bytes(content.get(pos..pos + 4)?.try_into().ok()?) as usize;
+        let chunk_type = content.get(pos + 4..pos + 8)?;
+        // Length, type, data and CRC
+        let end = pos + 12 + length;
+        let chunk = content.get(pos..end)?;
+
+        if !METADATA_CHUNKS
+            .iter()
+            .any(|name| name.as_slice() == chunk_type)
+        {
+            output.extend_from_slice(chunk);
+        }
+        pos = end;
+    }
+
+    Some(output)
+}
+
+/// Drops `EXIF` and `XMP ` chunks and clears their flags in the `VP8X` header
+fn strip_webp_metadata(content: &[u8]) -> Option<Vec<u8>> {
+    const HEADER_LEN: usize = 12;
+    const EXIF_FLAG: u8 = 0x08;
+    const XMP_FLAG: u8 = 0x04;
+
+    if content.get(..4)? != b"RIFF" || content.get(8..12)? != b"WEBP" {
+        return None;
+    }
+
+    let mut output = content[..HEADER_LEN].to_vec();
+    let mut pos = HEADER_LEN;
+    while pos < content.len() {
+        let chunk_type = content.get(pos..pos + 4)?;
+        let length = u32::from_le_bytes(content.get(pos + 4..pos + 8)?.try_into().ok()?) as usize;
+        // Chunks are padded to an even length
+        let end = (pos + 8 + length + length % 2).min(content.len());
+        let chunk = content.get(pos..end)?;
+
+        match chunk_type {
+            b"EXIF" | b"XMP " => {}
+            b"VP8X" => {
+                let flags_at = output.len() + 8;
+                output.extend_from_slice(chunk);
+                *output.get_mut(flags_at)? &= !(EXIF_FLAG | XMP_FLAG);
+            }
+            _ => output.extend_from_slice(chunk),
+        }
+        pos = end;
+    }
+
+    let riff_size = u32::try_from(output.len() - 8).ok()?;
+    output[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Some(output)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageMetadata {
+    pub path: String,
+    pub format: Option<String>, // `png`, `jpeg`, `webp`, `gif`, `avif` or `svg`
+    pub width: Option<u32>,     // Unknown for formats that can't be decoded, like SVG
+    pub height: Option<u32>,
+    pub size: u64,
+    pub dominant_color: Option<String>, // `#rrggbb`
+}
+
+/// Problems found by the image audit
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImageAudit {
+    pub images_checked: usize,
+    pub missing_alt: Vec<ImageIssue>,
+    pub missing_files: Vec<ImageIssue>,
+    pub oversized: Vec<OversizedImage>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageIssue {
+    pub document: String,
+    pub reference: String, // As written in the document
+    pub line: Option<usize>,
+    pub field: Option<String>, // Set for frontmatter `image()` fields
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OversizedImage {
+    pub path: String,
+    pub size: u64,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub documents: Vec<String>, // Where the image is used
+}
+
+/// Dimensions, format, size and dominant color of an image. `image_path` is a file path or
+/// a reference as written in `document_path`, like `../../assets/cover.png` or `@assets/cover.png`.
+#[tauri::command]
+pub async fn get_image_metadata(
+    project_path: String,
+    image_path: String,
+    document_path: Option<String>,
+) -> Result<ImageMetadata, String> {
+    let project_root = Path::new(&project_path)
+        .canonicalize()
+        .map_err(|_| "Invalid project root".to_string())?;
+
+    let direct = Path::new(&image_path);
+    let path = if direct.is_absolute() && direct.exists() {
+        direct.to_path_buf()
+    } else {
+        AssetResolver::new(&project_root)
+            .resolve(&image_path, document_path.as_deref().map(Path::new))
+            .ok_or_else(|| format!("Not a local image: {image_path}"))?
+    };
+
+    let path = path
+        .canonicalize()
+        .map_err(|_| format!("Image not found: {image_path}"))?;
+    path.strip_prefix(&project_root)
+        .map_err(|_| "Image outside project directory".to_string())?;
+
+    let content = std::fs::read(&path).map_err(|e| format!("Failed to read image: {e}"))?;
+    let (format, dimensions) = image_format_and_dimensions(&path, &content);
+
+    let dominant_color = format
+        .filter(|_| dimensions.is_some())
+        .and_then(|format| image::load_from_memory_with_format(&content, format).ok())
+        .map(|image| dominant_color(&image));
+
+    Ok(ImageMetadata {
+        path: path.to_string_lossy().to_string(),
+        format: format_name(&path, format),
+        width: dimensions.map(|(width, _)| width),
+        height: dimensions.map(|(_, height)| height),
+        size: content.len() as u64,
+        dominant_color,
+    })
+}
+
+/// Checks every image used in the project's content: images without alt text, references
+/// (including frontmatter `image()` fields) to files that don't exist, and images over
+/// `max_file_size` bytes or wider than `max_width` pixels
+#[tauri::command]
+pub async fn audit_images(
+    project_path: String,
+    content_directory: Option<String>,
+    max_file_size: Option<u64>,
+    max_width: Option<u32>,
+) -> Result<ImageAudit, String> {
+    let project_root = PathBuf::from(&project_path);
+    let resolver = AssetResolver::new(&project_root);
+    let collections =
+        scan_project_with_content_dir(project_path.clone(), content_directory).await?;
+
+    let mut audit = ImageAudit::default();
+    let mut used: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
+
+    for collection in &collections {
+        let image_fields = schema_image_fields(collection.schema.as_deref());
+        let files =
+            scan_collection_files(collection.path.to_string_lossy().to_string(), None).await?;
+
+        for file in files {
+            let Ok(content) = std::fs::read_to_string(&file.path) else {
+                continue;
+            };
+            let document = file.path.to_string_lossy().to_string();
+
+            // (reference, line, frontmatter field)
+            let mut references: Vec<(String, Option<usize>, Option<String>)> = Vec::new();
+            for reference in extract_body_references(&content) {
+                if reference.is_image
+                    && reference
+                        .alt
+                        .as_deref()
+                        .is_none_or(|alt| alt.trim().is_empty())
+                {
+                    audit.missing_alt.push(ImageIssue {
+                        document: document.clone(),
+                        reference: reference.target.clone(),
+                        line: Some(reference.line),
+                        field: None,
+                    });
+                }
+                if reference.is_image || has_image_extension(&reference.target) {
+                    references.push((reference.target, Some(reference.line), None));
+                }
+            }
+            for field in &image_fields {
+                let line = frontmatter_line(&content, field);
+                for value in frontmatter_strings(file.frontmatter.as_ref(), field) {
+                    references.push((value, line, Some(field.clone())));
+                }
+            }
+
+            for (reference, line, field) in references {
+                let Some(path) = resolver.resolve(&reference, Some(&file.path)) else {
+                    continue;
+                };
+                if path.is_file() {
+                    used.entry(path).or_default().push(document.clone());
+                } else {
+                    audit.missing_files.push(ImageIssue {
+                        document: document.clone(),
+                        reference,
+                        line,
+                        field,
+                    });
+                }
+            }
+        }
+    }
+
+    let max_file_size = max_file_size.unwrap_or(DEFAULT_MAX_FILE_SIZE);
+    let max_width = max_width.unwrap_or(DEFAULT_MAX_WIDTH);
+    audit.images_checked = used.len();
+
+    for (path, mut documents) in used {
+        let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        let dimensions = ImageReader::open(&path)
+            .and_then(|reader| reader.with_guessed_format())
+            .ok()
+            .and_then(|reader| reader.into_dimensions().ok());
+
+        if size > max_file_size || dimensions.is_some_and(|(width, _)| width > max_width) {
+            documents.dedup();
+            audit.oversized.push(OversizedImage {
+                path: path.to_string_lossy().to_string(),
+                size,
+                width: dimensions.map(|(width, _)| width),
+                height: dimensions.map(|(_, height)| height),
+                documents,
+            });
+        }
+    }
+
+    info!(
+        "Astro Editor [IMAGES] Audited {} images: {} without alt text, {} missing, {} oversized",
+        audit.images_checked,
+        audit.missing_alt.len(),
+        audit.missing_files.len(),
+        audit.oversized.len()
+    );
+    Ok(audit)
+}
+
+fn image_format_and_dimensions(
+    path: &Path,
+    content: &[u8],
+) -> (Option<ImageFormat>, Option<(u32, u32)>) {
+    let format = image::guess_format(content)
+        .ok()
+        .or_else(|| ImageFormat::from_path(path).ok());
+    let dimensions = format.and_then(|format| {
+        ImageReader::with_format(Cursor::new(content), format)
+            .into_dimensions()
+            .ok()
+    });
+    (format, dimensions)
+}
+
+fn format_name(path: &Path, format: Option<ImageFormat>) -> Option<String> {
+    match format {
+        Some(format) => Some(format!("{format:?}").to_lowercase()),
+        None => path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .filter(|ext| ext.eq_ignore_ascii_case("svg"))
+            .map(|_| "svg".to_string()),
+    }
+}
+
+/// The average color of the most common group of similar colors, ignoring transparency
+fn dominant_color(image: &DynamicImage) -> String {
+    let thumbnail = image.thumbnail(64, 64).to_rgba8();
+
+    // 4 bits per channel: bucket -> (count, summed r, g, b)
+    let mut buckets: HashMap<(u8, u8, u8), (u64, [u64; 3])> = HashMap::new();
+    for pixel in thumbnail.pixels().filter(|pixel| pixel[3] >= 128) {
+        let [r, g, b, _] = pixel.0;
+        let (count, sums) = buckets.entry((r >> 4, g >> 4, b >> 4)).or_default();
+        *count += 1;
+        for (sum, value) in sums.iter_mut().zip([r, g, b]) {
+            *sum += u64::from(value);
+        }
+    }
+
+    let Some((count, sums)) = buckets
+        .into_values()
+        .max_by_key(|(count, sums)| (*count, *sums))
+    else {
+        return "#000000".to_string();
+    };
+    let [r, g, b] = sums.map(|sum| sum / count);
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn has_image_extension(target: &str) -> bool {
+    let path = target.split(['?', '#']).next().unwrap_or(target);
+    Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// Names of the fields a collection schema declares with Astro's `image()` helper
+fn schema_image_fields(schema: Option<&str>) -> Vec<String> {
+    let Some(schema) = schema.and_then(|schema| serde_json::from_str::<Value>(schema).ok()) else {
+        return Vec::new();
+    };
+    schema["fields"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|field| field["constraints"]["transform"] == "astro-image")
+        .filter_map(|field| field["name"].as_str().map(str::to_string))
+        .collect()
+}
+
+/// String values of a frontmatter field; nested fields are written `cover.src`
+fn frontmatter_strings(frontmatter: Option<&HashMap<String, Value>>, field: &str) -> Vec<String> {
+    let mut parts = field.split('.');
+    let Some(mut value) = parts.next().and_then(|first| frontmatter?.get(first)) else {
+        return Vec::new();
+    };
+    for part in parts {
+        match value.get(part) {
+            Some(nested) => value = nested,
+            None => return Vec::new(),
+        }
+    }
+
+    match value {
+        Value::String(s) => vec![s.clone()],
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| item.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// The 1-based line a top-level frontmatter field starts on
+fn frontmatter_line(content: &str, field: &str) -> Option<usize> {
+    let top_level = field.split('.').next()?;
+    content
+        .lines()
+        .skip(1)
+        .take_while(|line| line.trim_end() != "---")
+        .position(|line| line.starts_with(&format!("{top_level}:")))
+        .map(|index| index + 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, RgbImage};
+
+    /// A JPEG with an EXIF segment saying the image is rotated 90 degrees
+    fn rotated_jpeg(width: u32, height: u32) -> Vec<u8> {
+        let mut jpeg = Vec::new();
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut jpeg, 90))
+            .unwrap();
+
+        // Big-endian TIFF with one IFD entry: Orientation (0x0112) = 6
+        let mut exif = b"Exif\0\0MM\0\x2A\0\0\0\x08\0\x01".to_vec();
+        exif.extend_from_slice(&[0x01, 0x12, 0x00, 0x03, 0, 0, 0, 1, 0, 6, 0, 0]);
+        exif.extend_from_slice(&[0, 0, 0, 0]);
+        let mut segment = vec![0xFF, 0xE1];
+        segment.extend_from_slice(&((exif.len() + 2) as u16).to_be_bytes());
+        segment.extend_from_slice(&exif);
+
+        jpeg.splice(2..2, segment);
+        jpeg
+    }
+
+    fn decode(content: &[u8]) -> DynamicImage {
+        image::load_from_memory(content).unwrap()
+    }
+
+    #[test]
+    fn test_strip_metadata() {
+        let jpeg = rotated_jpeg(4, 2);
+        let stripped = strip_jpeg_metadata(&jpeg).unwrap();
+        assert_eq!(stripped.len(), jpeg.len() - 36);
+        assert_eq!(decode(&stripped).dimensions(), (4, 2));
+
+        // Rotated images are re-encoded upright, so they still display the same way
+        let options = ImageProcessing {
+            strip_metadata: Some(true),
+            ..Default::default()
+        };
+        let processed = process_image(&jpeg, &options).unwrap().unwrap();
+        assert_eq!(processed.extension, None);
+        let mut decoder = ImageReader::new(Cursor::new(&processed.content))
+            .with_guessed_format()
+            .unwrap()
+            .into_decoder()
+            .unwrap();
+        assert_eq!(decoder.orientation().unwrap(), Orientation::NoTransforms);
+        assert_eq!(decoder.dimensions(), (2, 4));
+    }
+
+    #[test]
+    fn test_resize_and_convert() {
+        let mut png = Vec::new();
+        DynamicImage::ImageRgb8(RgbImage::new(400, 200))
+            .write_with_encoder(PngEncoder::new(&mut png))
+            .unwrap();
+
+        let options = ImageProcessing {
+            max_width: Some(100),
+            convert_to: Some(ImageOutputFormat::Webp),
+            ..Default::default()
+        };
+        let processed = process_image(&png, &options).unwrap().unwrap();
+        assert_eq!(processed.extension, Some("webp"));
+        assert_eq!(
+            image::guess_format(&processed.content).unwrap(),
+            ImageFormat::WebP
+        );
+        assert_eq!(decode(&processed.content).dimensions(), (100, 50));
+
+        // Already small enough, and nothing else to do
+        let options = ImageProcessing {
+            max_width: Some(1000),
+            ..Default::default()
+        };
+        assert!(process_image(&png, &options).unwrap().is_none());
+        assert!(process_image(b"<svg/>", &options).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_image_metadata_and_audit() {
+        use std::fs;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let blog = root.join("src/content/blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::create_dir_all(root.join("src/assets")).unwrap();
+        fs::write(
+            root.join("src/content/config.ts"),
+            r#"import { defineCollection, z } from 'astro:content';
+const blog = defineCollection({
+  schema: ({ image }) => z.object({
+    title: z.string(),
+    cover: image(),
+  }),
+});
+export const collections = { blog };"#,
+        )
+        .unwrap();
+
+        let mut red = RgbImage::new(3000, 10);
+        for pixel in red.pixels_mut() {
+            pixel.0 = [200, 10, 10];
+        }
+        DynamicImage::ImageRgb8(red)
+            .save(root.join("src/assets/wide.png"))
+            .unwrap();
+        fs::write(
+            blog.join("post.md"),
+            "---\ntitle: Post\ncover: ../../assets/missing.png\n---\n\n![](../../assets/wide.png)\n\n![Described](../../assets/wide.png)\n",
+        )
+        .unwrap();
+
+        let metadata = get_image_metadata(
+            root.to_string_lossy().to_string(),
+            "../../assets/wide.png".to_string(),
+            Some(blog.join("post.md").to_string_lossy().to_string()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(metadata.format.as_deref(), Some("png"));
+        assert_eq!((metadata.width, metadata.height), (Some(3000), Some(10)));
+        assert_eq!(metadata.dominant_color.as_deref(), Some("#c80a0a"));
+
+        let audit = audit_images(root.to_string_lossy().to_string(), None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(audit.images_checked, 1);
+        assert_eq!(audit.missing_alt.len(), 1);
+        assert_eq!(audit.missing_alt[0].line, Some(6));
+        assert_eq!(audit.missing_files.len(), 1);
+        assert_eq!(audit.missing_files[0].field.as_deref(), Some("cover"));
+        assert_eq!(audit.missing_files[0].line, Some(3));
+        assert_eq!(audit.oversized.len(), 1);
+        assert_eq!(audit.oversized[0].documents.len(), 1);
+    }
+}
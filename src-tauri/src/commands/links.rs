@@ -0,0 +1,645 @@
+use crate::commands::project::{scan_collection_files, scan_project_with_content_dir};
+use crate::commands::watcher::write_tracked;
+use crate::models::FileEntry;
+use crate::paths::{normalize_path, relative_path, to_slash_path};
+use log::{debug, info, warn};
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkKind {
+    /// A path relative to the linking file, e.g. `../blog/post.md`
+    Relative,
+    /// A site route, e.g. `/blog/my-post`
+    Route,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentLink {
+    pub source: String,
+    pub target: String, // The link target exactly as written
+    pub resolved: Option<String>,
+    pub kind: LinkKind,
+    pub line: usize, // 1-based
+    pub text: String,
+    /// Whether the link matched on the file name rather than a `slug` frontmatter field
+    #[serde(skip)]
+    matched_file_name: bool,
+}
+
+/// All internal links between content files in a project
+#[derive(Debug, Default)]
+pub struct LinkGraph {
+    pub links: Vec<ContentLink>,
+}
+
+impl LinkGraph {
+    pub fn backlinks(&self, file_path: &Path) -> Vec<&ContentLink> {
+        self.links
+            .iter()
+            .filter(|link| link.resolved.as_deref().map(Path::new) == Some(file_path))
+            .collect()
+    }
+
+    pub fn broken_links(&self) -> Vec<&ContentLink> {
+        self.links
+            .iter()
+            .filter(|link| link.resolved.is_none())
+            .collect()
+    }
+}
+
+#[tauri::command]
+pub async fn get_backlinks(
+    project_path: String,
+    file_path: String,
+    content_directory: Option<String>,
+) -> Result<Vec<ContentLink>, String> {
+    let graph = build_link_graph(&project_path, content_directory).await?;
+    let target = normalize_path(Path::new(&file_path));
+    Ok(graph.backlinks(&target).into_iter().cloned().collect())
+}
+
+#[tauri::command]
+pub async fn get_broken_links(
+    project_path: String,
+    content_directory: Option<String>,
+) -> Result<Vec<ContentLink>, String> {
+    let graph = build_link_graph(&project_path, content_directory).await?;
+    Ok(graph.broken_links().into_iter().cloned().collect())
+}
+
+/// Parses every content file in the project and resolves the links between them
+pub async fn build_link_graph(
+    project_path: &str,
+    content_directory: Option<String>,
+) -> Result<LinkGraph, String> {
+    let collections =
+        scan_project_with_content_dir(project_path.to_string(), content_directory).await?;
+
+    let mut files_by_collection: HashMap<String, Vec<FileEntry>> = HashMap::new();
+    for collection in &collections {
+        let files =
+            scan_collection_files(collection.path.to_string_lossy().to_string(), None).await?;
+        files_by_collection.insert(collection.name.clone(), files);
+    }
+
+    let resolver = LinkResolver::new(Path::new(project_path), files_by_collection);
+    let mut graph = LinkGraph::default();
+
+    for file in resolver.all_files() {
+        let Ok(content) = std::fs::read_to_string(&file.path) else {
+            continue;
+        };
+        // Images point at assets, not content
+        for raw in extract_links(&content)
+            .into_iter()
+            .filter(|raw| !raw.is_image)
+        {
+            if let Some(link) = resolver.resolve(&file.path, raw) {
+                graph.links.push(link);
+            }
+        }
+    }
+
+    debug!(
+        "Astro Editor [LINKS] Built link graph with {} internal links",
+        graph.links.len()
+    );
+
+    Ok(graph)
+}
+
+/// Link edits needed in other files after a content file moves
+#[derive(Debug)]
+pub struct LinkRewrites {
+    old_path: PathBuf,
+    /// source file -> (old target, new target)
+    replacements: HashMap<String, Vec<(String, String)>>,
+}
+
+/// Works out how links in other files that point at `old_path` have to change for `new_path`
+///
+/// Must run before the file is moved, while links to the old path still resolve. Nothing is
+/// written until [`LinkRewrites::apply`] is called, so the rename can happen in between.
+pub async fn collect_link_rewrites(
+    project_root: &str,
+    content_directory: Option<String>,
+    old_path: &Path,
+    new_path: &Path,
+) -> Result<LinkRewrites, String> {
+    let graph = build_link_graph(project_root, content_directory).await?;
+    let old_path = normalize_path(old_path);
+    let new_path = normalize_path(new_path);
+
+    let mut replacements: HashMap<String, Vec<(String, String)>> = HashMap::new();
+    for link in graph.backlinks(&old_path) {
+        // Relative links inside the renamed file itself can't be retargeted this way
+        if Path::new(&link.source) == old_path {
+            continue;
+        }
+        if let Some(new_target) = retarget_link(link, &new_path) {
+            replacements
+                .entry(link.source.clone())
+                .or_default()
+                .push((link.target.clone(), new_target));
+        }
+    }
+
+    Ok(LinkRewrites {
+        old_path,
+        replacements,
+    })
+}
+
+impl LinkRewrites {
+    /// Writes the rewritten links. A file that can't be updated doesn't stop the others;
+    /// it's reported in `failed`.
+    pub fn apply(self) -> LinkRewriteResult {
+        let mut result = LinkRewriteResult::default();
+        for (source, replacements) in self.replacements {
+            match rewrite_file_links(&source, &replacements) {
+                Ok(true) => result.updated.push(source),
+                Ok(false) => {}
+                Err(error) => {
+                    warn!("Astro Editor [LINKS] Failed to update links in {source}: {error}");
+                    result.failed.push(LinkRewriteFailure {
+                        path: source,
+                        error,
+                    });
+                }
+            }
+        }
+
+        info!(
+            "Astro Editor [LINKS] Updated links in {} files after renaming {}",
+            result.updated.len(),
+            self.old_path.display()
+        );
+
+        result
+    }
+}
+
+/// Files whose links were updated after a rename
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LinkRewriteResult {
+    pub updated: Vec<String>,
+    pub failed: Vec<LinkRewriteFailure>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LinkRewriteFailure {
+    pub path: String,
+    pub error: String,
+}
+
+/// Returns whether the file changed
+fn rewrite_file_links(source: &str, replacements: &[(String, String)]) -> Result<bool, String> {
+    let content =
+        std::fs::read_to_string(source).map_err(|e| format!("Failed to read file: {e}"))?;
+    let new_content = replace_link_targets(&content, replacements);
+    if new_content == content {
+        return Ok(false);
+    }
+    write_tracked(Path::new(source), new_content)
+        .map_err(|e| format!("Failed to write file: {e}"))?;
+    Ok(true)
+}
+
+/// A link or image found in a Markdown or MDX document
+pub(crate) struct RawLink<'a> {
+    pub(crate) target: &'a str, // As written
+    pub(crate) text: &'a str,   // The alt text, for images
+    pub(crate) is_image: bool,
+    pub(crate) line: usize, // 1-based
+}
+
+struct LinkPatterns {
+    inline: Regex,
+    reference: Regex,
+    anchor: Regex,
+}
+
+static LINK_PATTERNS: OnceLock<LinkPatterns> = OnceLock::new();
+
+fn link_patterns() -> &'static LinkPatterns {
+    LINK_PATTERNS.get_or_init(|| LinkPatterns {
+        inline: Regex::new(r"(!?)\[([^\]]*)\]\(\s*<?([^)\s>]+)>?(?:\s+[^)]*)?\)").unwrap(),
+        reference: Regex::new(r"^\s{0,3}\[([^\]]+)\]:\s*<?(\S+?)>?(?:\s|$)").unwrap(),
+        anchor: Regex::new(r#"<a\s[^>]*href=["']([^"']+)["'][^>]*>([^<]*)"#).unwrap(),
+    })
+}
+
+/// Finds Markdown links and images, reference definitions and `<a href>` tags outside
+/// code blocks
+pub(crate) fn extract_links(content: &str) -> Vec<RawLink<'_>> {
+    let patterns = link_patterns();
+    let mut links = Vec::new();
+    for (line_number, line) in lines_outside_code_blocks(content) {
+        for cap in patterns.inline.captures_iter(line) {
+            links.push(RawLink {
+                target: cap.get(3).unwrap().as_str(),
+                text: cap.get(2).unwrap().as_str(),
+                is_image: cap.get(1).is_some_and(|m| !m.as_str().is_empty()),
+                line: line_number,
+            });
+        }
+        if let Some(cap) = patterns.reference.captures(line) {
+            links.push(RawLink {
+                target: cap.get(2).unwrap().as_str(),
+                text: cap.get(1).unwrap().as_str(),
+                is_image: false,
+                line: line_number,
+            });
+        }
+        for cap in patterns.anchor.captures_iter(line) {
+            links.push(RawLink {
+                target: cap.get(1).unwrap().as_str(),
+                text: cap.get(2).unwrap().as_str(),
+                is_image: false,
+                line: line_number,
+            });
+        }
+    }
+
+    links
+}
+
+/// The lines of a document outside fenced code blocks, with 1-based line numbers
+pub(crate) fn lines_outside_code_blocks(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut in_code_block = false;
+    content
+        .lines()
+        .enumerate()
+        .filter_map(move |(index, line)| {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code_block = !in_code_block;
+                return None;
+            }
+            (!in_code_block).then_some((index + 1, line))
+        })
+}
+
+struct LinkResolver {
+    project_root: PathBuf,
+    files_by_collection: HashMap<String, Vec<FileEntry>>,
+}
+
+impl LinkResolver {
+    fn new(project_root: &Path, files_by_collection: HashMap<String, Vec<FileEntry>>) -> Self {
+        Self {
+            project_root: normalize_path(project_root),
+            files_by_collection,
+        }
+    }
+
+    fn all_files(&self) -> impl Iterator<Item = &FileEntry> {
+        self.files_by_collection.values().flatten()
+    }
+
+    fn is_content_file(&self, path: &Path) -> bool {
+        self.all_files()
+            .any(|file| normalize_path(&file.path) == path)
+    }
+
+    /// Resolves a raw link from `source`. Returns `None` for links that don't target content.
+    fn resolve(&self, source: &Path, raw: RawLink) -> Option<ContentLink> {
+        let path_part = link_path(raw.target)?;
+
+        let (kind, resolved, matched_file_name) = if path_part.starts_with('/') {
+            if has_markdown_extension(path_part) {
+                let candidate =
+                    normalize_path(&self.project_root.join(path_part.trim_start_matches('/')));
+                let found = self.is_content_file(&candidate).then_some(candidate);
+                (LinkKind::Relative, found, true)
+            } else {
+                let (resolved, matched_file_name) = self.resolve_route(path_part)?;
+                (LinkKind::Route, resolved, matched_file_name)
+            }
+        } else {
+            let base = normalize_path(&source.parent()?.join(path_part));
+            let resolved = if has_markdown_extension(path_part) {
+                self.is_content_file(&base).then_some(base)
+            } else if Path::new(path_part).extension().is_none() {
+                // Extensionless relative links only count if they actually reach a content file
+                let found = ["md", "mdx"]
+                    .iter()
+                    .map(|ext| base.with_extension(ext))
+                    .find(|candidate| self.is_content_file(candidate));
+                Some(found?)
+            } else {
+                return None;
+            };
+            (LinkKind::Relative, resolved, true)
+        };
+
+        Some(ContentLink {
+            source: source.to_string_lossy().to_string(),
+            target: raw.target.to_string(),
+            resolved: resolved.map(|p| p.to_string_lossy().to_string()),
+            kind,
+            line: raw.line,
+            text: raw.text.to_string(),
+            matched_file_name,
+        })
+    }
+
+    /// Resolves `/collection/slug` routes. Routes outside known collections are ignored.
+    fn resolve_route(&self, route: &str) -> Option<(Option<PathBuf>, bool)> {
+        let trimmed = route.trim_matches('/');
+        let (collection, slug) = trimmed.split_once('/')?;
+        let files = self.files_by_collection.get(collection)?;
+        let slug = slug.trim_end_matches(".html");
+
+        let by_frontmatter = files.iter().find(|file| {
+            file.frontmatter
+                .as_ref()
+                .and_then(|frontmatter| frontmatter.get("slug"))
+                .and_then(|value| value.as_str())
+                .is_some_and(|value| value.trim_matches('/') == slug)
+        });
+        if let Some(file) = by_frontmatter {
+            return Some((Some(normalize_path(&file.path)), false));
+        }
+
+        let by_name = files.iter().find(|file| file.name == slug);
+        Some((by_name.map(|file| normalize_path(&file.path)), true))
+    }
+}
+
+/// Strips the fragment and query from a link target, skipping external and anchor-only links
+fn link_path(target: &str) -> Option<&str> {
+    let target = target.trim();
+    if target.starts_with("//")
+        || target.contains("://")
+        || ["mailto:", "tel:", "data:", "javascript:"]
+            .iter()
+            .any(|scheme| target.starts_with(scheme))
+    {
+        return None;
+    }
+
+    let path = target.split(['#', '?']).next().unwrap_or("");
+    (!path.is_empty()).then_some(path)
+}
+
+fn has_markdown_extension(path: &str) -> bool {
+    matches!(
+        Path::new(path).extension().and_then(|ext| ext.to_str()),
+        Some("md") | Some("mdx")
+    )
+}
+
+/// Works out what a link should point to once its target has moved to `new_path`
+fn retarget_link(link: &ContentLink, new_path: &Path) -> Option<String> {
+    let original_path = link_path(&link.target)?;
+    let suffix = &link.target[link.target.find(original_path)? + original_path.len()..];
+
+    let new_link_path = match link.kind {
+        LinkKind::Route => {
+            // Routes based on a frontmatter slug don't change when the file is renamed
+            if !link.matched_file_name {
+                return None;
+            }
+            // The route's collection stays the same; nested folders aren't part of the route
+            let collection = original_path.trim_start_matches('/').split('/').next()?;
+            let slug = new_path.file_stem()?.to_str()?;
+            let trailing_slash = if original_path.ends_with('/') {
+                "/"
+            } else {
+                ""
+            };
+            format!("/{collection}/{slug}{trailing_slash}")
+        }
+        LinkKind::Relative if original_path.starts_with('/') => {
+            // Project-root-absolute path: keep it absolute
+            let old_resolved = Path::new(link.resolved.as_ref()?);
+            let root = strip_suffix_path(old_resolved, original_path.trim_start_matches('/'))?;
+            format!("/{}", to_slash_path(new_path.strip_prefix(root).ok()?))
+        }
+        LinkKind::Relative => {
+            let source_dir = Path::new(&link.source).parent()?.to_path_buf();
+            let mut relative = relative_path(&source_dir, new_path);
+            if Path::new(original_path).extension().is_none() {
+                relative.set_extension("");
+            }
+            let relative = to_slash_path(&relative);
+            if original_path.starts_with("./") && !relative.starts_with("../") {
+                format!("./{relative}")
+            } else {
+                relative
+            }
+        }
+    };
+
+    Some(format!("{new_link_path}{suffix}"))
+}
+
+/// Removes `suffix` (a relative path) from the end of `path`, returning the remaining prefix
+fn strip_suffix_path<'a>(path: &'a Path, suffix: &str) -> Option<&'a Path> {
+    let depth = Path::new(suffix).components().count();
+    let mut prefix = path;
+    for _ in 0..depth {
+        prefix = prefix.parent()?;
+    }
+    Some(prefix)
+}
+
+/// Replaces link targets in inline links, `href` attributes and reference definitions,
+/// in one pass over the document
+fn replace_link_targets(content: &str, replacements: &[(String, String)]) -> String {
+    if replacements.is_empty() {
+        return content.to_string();
+    }
+    let new_targets: HashMap<&str, &str> = replacements
+        .iter()
+        .map(|(old, new)| (old.as_str(), new.as_str()))
+        .collect();
+    let targets = replacements
+        .iter()
+        .map(|(old, _)| regex::escape(old))
+        .collect::<Vec<_>>()
+        .join("|");
+
+    let pattern = Regex::new(&format!(
+        r#"(?m)(\]\(\s*<?)({targets})(>?[\s)])|(href=["'])({targets})(["'])|(^\s{{0,3}}\[[^\]]+\]:\s*<?)({targets})(>?(?:\s|$))"#
+    ))
+    .unwrap();
+
+    pattern
+        .replace_all(content, |cap: &Captures| {
+            // Each form captures (prefix, target, suffix); only one of them matches
+            let group = (1..=7).step_by(3).find(|&i| cap.get(i).is_some()).unwrap();
+            let target = &cap[group + 1];
+            format!(
+                "{}{}{}",
+                &cap[group],
+                new_targets.get(target).copied().unwrap_or(target),
+                &cap[group + 2]
+            )
+        })
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_project() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let blog = temp_dir.path().join("src/content/blog");
+        let notes = temp_dir.path().join("src/content/notes");
+        fs::create_dir_all(&blog).unwrap();
+        fs::create_dir_all(&notes).unwrap();
+
+        fs::write(
+            blog.join("first-post.md"),
+            "---\ntitle: First\n---\n\nSee the [second post](/blog/second-post/#intro).\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("second-post.md"),
+            "---\ntitle: Second\n---\n\nBack to [first](./first-post.md) and [missing](./nope.md).\n\n```md\n[ignored](./first-post.md)\n```\n",
+        )
+        .unwrap();
+        fs::write(
+            notes.join("note.mdx"),
+            "---\ntitle: Note\n---\n\n<a href=\"/blog/first-post\">First</a> and [gone](/blog/deleted) and [about](/about) and [web](https://example.com).\n\n[ref]: ../blog/second-post\n",
+        )
+        .unwrap();
+
+        temp_dir
+    }
+
+    #[test]
+    fn test_replace_link_targets() {
+        let content = "[a](./old.md) and [b](<./old.md> \"Title\") and [c](./older.md)\n<a href='/blog/old'>A</a>\n\n[ref]: /blog/old\n";
+        let replaced = replace_link_targets(
+            content,
+            &[
+                ("./old.md".to_string(), "./new.md".to_string()),
+                ("/blog/old".to_string(), "/blog/$new".to_string()),
+            ],
+        );
+        assert_eq!(
+            replaced,
+            "[a](./new.md) and [b](<./new.md> \"Title\") and [c](./older.md)\n<a href='/blog/$new'>A</a>\n\n[ref]: /blog/$new\n"
+        );
+    }
+
+    #[test]
+    fn test_link_path_skips_external_links() {
+        assert_eq!(link_path("https://example.com/blog/post"), None);
+        assert_eq!(link_path("mailto:me@example.com"), None);
+        assert_eq!(link_path("#heading"), None);
+        assert_eq!(link_path("./post.md#heading"), Some("./post.md"));
+        assert_eq!(link_path("/blog/post?ref=1"), Some("/blog/post"));
+    }
+
+    #[tokio::test]
+    async fn test_backlinks_and_broken_links() {
+        let project = create_project();
+        let project_path = project.path().to_string_lossy().to_string();
+        let first = project.path().join("src/content/blog/first-post.md");
+
+        let backlinks = get_backlinks(
+            project_path.clone(),
+            first.to_string_lossy().to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        // The link inside the code block is ignored
+        assert_eq!(backlinks.len(), 2);
+        assert!(backlinks
+            .iter()
+            .any(|l| l.kind == LinkKind::Relative && l.source.ends_with("second-post.md")));
+        assert!(backlinks
+            .iter()
+            .any(|l| l.kind == LinkKind::Route && l.source.ends_with("note.mdx")));
+
+        let mut broken: Vec<String> = get_broken_links(project_path, None)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|l| l.target)
+            .collect();
+        broken.sort();
+
+        // `/about` isn't a collection route, so it's not reported as broken
+        assert_eq!(broken, vec!["./nope.md", "/blog/deleted"]);
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_links_for_rename() {
+        let project = create_project();
+        let project_path = project.path().to_string_lossy().to_string();
+        let blog = project.path().join("src/content/blog");
+
+        let rewrites = collect_link_rewrites(
+            &project_path,
+            None,
+            &blog.join("second-post.md"),
+            &blog.join("renamed-post.md"),
+        )
+        .await
+        .unwrap();
+
+        // Nothing is written until the rewrites are applied
+        let first = fs::read_to_string(blog.join("first-post.md")).unwrap();
+        assert!(first.contains("[second post](/blog/second-post/#intro)"));
+
+        fs::rename(blog.join("second-post.md"), blog.join("renamed-post.md")).unwrap();
+        let updated = rewrites.apply().updated;
+
+        assert_eq!(updated.len(), 2);
+
+        let first = fs::read_to_string(blog.join("first-post.md")).unwrap();
+        assert!(first.contains("[second post](/blog/renamed-post/#intro)"));
+
+        let note = fs::read_to_string(project.path().join("src/content/notes/note.mdx")).unwrap();
+        assert!(note.contains("[ref]: ../blog/renamed-post\n"));
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_route_for_nested_file() {
+        let project = create_project();
+        let project_path = project.path().to_string_lossy().to_string();
+        // Entries in locale folders are nested inside the collection
+        let blog = project.path().join("src/content/blog");
+        let nested = blog.join("en");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir_all(blog.join("fr")).unwrap();
+        fs::write(nested.join("launch.md"), "---\ntitle: Launch\n---\n").unwrap();
+        fs::write(blog.join("fr/lancement.md"), "---\ntitle: Lancement\n---\n").unwrap();
+        let notes = project.path().join("src/content/notes");
+        fs::write(
+            notes.join("launch-note.md"),
+            "Read [the launch](/blog/launch).\n",
+        )
+        .unwrap();
+
+        let rewrites = collect_link_rewrites(
+            &project_path,
+            None,
+            &nested.join("launch.md"),
+            &nested.join("release.md"),
+        )
+        .await
+        .unwrap();
+        rewrites.apply();
+
+        // The route keeps its collection rather than the `en` folder name
+        let note = fs::read_to_string(notes.join("launch-note.md")).unwrap();
+        assert_eq!(note, "Read [the launch](/blog/release).\n");
+    }
+}
@@ -1,14 +1,19 @@
-use crate::models::{MdxComponent, PropInfo};
+use crate::astro_config::load_astro_project_config;
+use crate::models::{ComponentFramework, MdxComponent, PropInfo};
+use crate::project_ignore::project_walker;
+use regex::Regex;
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
+use std::sync::OnceLock;
+use swc_common::comments::{CommentKind, Comments, SingleThreadedComments};
 use swc_common::sync::Lrc;
-use swc_common::{FileName, SourceMap};
+use swc_common::{BytePos, FileName, SourceMap, Span, Spanned};
 use swc_ecma_ast::EsVersion;
 use swc_ecma_ast::*;
 use swc_ecma_parser::{parse_file_as_module, Syntax, TsSyntax};
 use swc_ecma_visit::{Visit, VisitWith};
-use walkdir::WalkDir;
 
 /// Validates that a file path is within the project boundaries
 ///
@@ -35,9 +40,11 @@ fn validate_project_path(file_path: &Path, project_root: &Path) -> Result<PathBu
 pub async fn scan_mdx_components(
     project_path: String,
     mdx_directory: Option<String>,
+    ignore_patterns: Option<Vec<String>>,
 ) -> Result<Vec<MdxComponent>, String> {
     let project_root = Path::new(&project_path);
-    let mdx_dir_path = mdx_directory.unwrap_or_else(|| "src/components/mdx".to_string());
+    let mdx_dir_path =
+        mdx_directory.unwrap_or_else(|| load_astro_project_config(project_root).mdx_directory());
     let mdx_dir = project_root.join(&mdx_dir_path);
 
     // Validate the MDX directory is within project bounds
@@ -51,17 +58,21 @@ pub async fn scan_mdx_components(
 
     let mut components = Vec::new();
 
-    for entry in WalkDir::new(&mdx_dir)
-        .follow_links(true)
-        .into_iter()
-        .filter_map(|e| e.ok())
-    {
+    let walker = project_walker(
+        &mdx_dir,
+        project_root,
+        ignore_patterns.as_deref().unwrap_or_default(),
+    )
+    .follow_links(true)
+    .build();
+
+    for entry in walker.filter_map(|e| e.ok()) {
         let path = entry.path();
 
-        // Only process .astro files
-        if path.extension().and_then(|s| s.to_str()) != Some("astro") {
+        // Only process component files
+        let Some(framework) = component_framework(path) else {
             continue;
-        }
+        };
 
         // Skip index files
         if path.file_stem().and_then(|s| s.to_str()) == Some("index") {
@@ -70,7 +81,7 @@ pub async fn scan_mdx_components(
 
         // Validate each component file is within project bounds
         match validate_project_path(path, project_root) {
-            Ok(_) => match parse_astro_component(path, &project_path) {
+            Ok(_) => match parse_component(path, &project_path, framework) {
                 Ok(component) => components.push(component),
                 Err(e) => eprintln!("Error parsing MDX component {}: {e}", path.display()),
             },
@@ -94,7 +105,22 @@ pub async fn scan_mdx_components(
     Ok(components)
 }
 
-fn parse_astro_component(path: &Path, project_root: &str) -> Result<MdxComponent, String> {
+/// Astro, React (`.tsx`/`.jsx`), Vue and Svelte components
+pub(crate) fn component_framework(path: &Path) -> Option<ComponentFramework> {
+    match path.extension().and_then(|s| s.to_str())? {
+        "astro" => Some(ComponentFramework::Astro),
+        "tsx" | "jsx" => Some(ComponentFramework::React),
+        "vue" => Some(ComponentFramework::Vue),
+        "svelte" => Some(ComponentFramework::Svelte),
+        _ => None,
+    }
+}
+
+fn parse_component(
+    path: &Path,
+    project_root: &str,
+    framework: ComponentFramework,
+) -> Result<MdxComponent, String> {
     // Validate the component file path is within project bounds
     let project_root_path = Path::new(project_root);
     let _validated_path = validate_project_path(path, project_root_path)?;
@@ -108,14 +134,29 @@ fn parse_astro_component(path: &Path, project_root: &str) -> Result<MdxComponent
         .ok_or("Invalid filename")?
         .to_string();
 
-    // Extract frontmatter (TypeScript code between ---)
-    let frontmatter = extract_frontmatter(&content)?;
+    let code = match framework {
+        // Extract frontmatter (TypeScript code between ---)
+        ComponentFramework::Astro => extract_frontmatter(&content)?,
+        ComponentFramework::React => content.clone(),
+        ComponentFramework::Vue | ComponentFramework::Svelte => extract_script_blocks(&content),
+    };
 
     // Parse TypeScript AST
-    let props = parse_props_from_typescript(&frontmatter)?;
+    let script = parse_component_script(&code, framework, &component_name)?;
+
+    // Check for <slot /> (or a `children` prop) in the template part
+    let has_slot = match framework {
+        ComponentFramework::Astro | ComponentFramework::Vue => content.contains("<slot"),
+        ComponentFramework::React => script.has_children,
+        ComponentFramework::Svelte => {
+            content.contains("<slot") || content.contains("{@render") || script.has_children
+        }
+    };
 
-    // Check for <slot /> in the template part
-    let has_slot = content.contains("<slot") || content.contains("<slot/>");
+    // Framework components are rendered to static HTML unless they're hydrated
+    let client_directive = script
+        .client_directive
+        .or_else(|| is_interactive(&content, framework).then(|| "client:load".to_string()));
 
     // Calculate relative path
     let relative_path = path
@@ -127,9 +168,11 @@ fn parse_astro_component(path: &Path, project_root: &str) -> Result<MdxComponent
     Ok(MdxComponent {
         name: component_name,
         file_path: relative_path,
-        props,
+        props: script.props,
         has_slot,
-        description: None, // TODO: Extract from JSDoc comments
+        description: script.description,
+        framework,
+        client_directive,
     })
 }
 
@@ -143,7 +186,76 @@ fn extract_frontmatter(content: &str) -> Result<String, String> {
     Ok(parts[1].to_string())
 }
 
-fn parse_props_from_typescript(typescript_code: &str) -> Result<Vec<PropInfo>, String> {
+struct ComponentPatterns {
+    script: Regex,
+    react_interactive: Regex,
+    vue_interactive: Regex,
+    svelte_interactive: Regex,
+}
+
+static COMPONENT_PATTERNS: OnceLock<ComponentPatterns> = OnceLock::new();
+
+fn component_patterns() -> &'static ComponentPatterns {
+    COMPONENT_PATTERNS.get_or_init(|| ComponentPatterns {
+        script: Regex::new(r"(?s)<script\b[^>]*>(.*?)</script>").unwrap(),
+        react_interactive: Regex::new(r"\buse[A-Z]\w*\(|\son[A-Z]\w*=\{").unwrap(),
+        vue_interactive: Regex::new(
+            r"\b(ref|reactive|computed|watch|watchEffect|onMounted)\(|\s(@|v-on:|v-model)",
+        )
+        .unwrap(),
+        svelte_interactive: Regex::new(r"\$(state|effect|derived)\(|\son:\w+=|\son[a-z]+=\{|\$:")
+            .unwrap(),
+    })
+}
+
+/// The code of every `<script>` block in a Vue or Svelte component
+fn extract_script_blocks(content: &str) -> String {
+    component_patterns()
+        .script
+        .captures_iter(content)
+        .map(|cap| cap[1].to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Whether a framework component has state or event handlers, and so needs hydrating
+fn is_interactive(content: &str, framework: ComponentFramework) -> bool {
+    let patterns = component_patterns();
+    let pattern = match framework {
+        ComponentFramework::Astro => return false,
+        ComponentFramework::React => &patterns.react_interactive,
+        ComponentFramework::Vue => &patterns.vue_interactive,
+        ComponentFramework::Svelte => &patterns.svelte_interactive,
+    };
+    pattern.is_match(content)
+}
+
+/// Props and description found in a component's script
+struct ComponentScript {
+    props: Vec<PropInfo>,
+    description: Option<String>,
+    has_children: bool, // React and Svelte 5 take slot content as a `children` prop
+    client_directive: Option<String>, // From a `@client` JSDoc tag
+}
+
+/// Reads a component's props and defaults, and JSDoc descriptions of the component and its
+/// props. Where the props come from depends on the framework:
+///
+/// - Astro: the `Props` interface or type alias, with defaults from destructuring `Astro.props`
+/// - React: the typed first parameter (or `FC<Props>` type) of the component function,
+///   with defaults from destructuring that parameter
+/// - Svelte: `export let` declarations, or the type of `let { ... } = $props()`
+/// - Vue: `defineProps<Props>()` (with `withDefaults` or destructuring defaults), or
+///   runtime `defineProps({ ... })` declarations
+///
+/// A prop's `@default` tag is used when the code gives no default. The component
+/// description is the JSDoc comment on the component function, on its props type, or at the
+/// top of the script.
+fn parse_component_script(
+    typescript_code: &str,
+    framework: ComponentFramework,
+    component_name: &str,
+) -> Result<ComponentScript, String> {
     // Create a source map (required by swc)
     let cm = Lrc::new(SourceMap::default());
 
@@ -160,37 +272,632 @@ fn parse_props_from_typescript(typescript_code: &str) -> Result<Vec<PropInfo>, S
         ..Default::default()
     });
 
-    let module = parse_file_as_module(&fm, syntax, EsVersion::Es2022, None, &mut vec![])
+    let comments = SingleThreadedComments::default();
+    let module = parse_file_as_module(&fm, syntax, EsVersion::Es2022, Some(&comments), &mut vec![])
         .map_err(|e| format!("Failed to parse TypeScript: {e:?}"))?;
 
-    // Find Props interface
-    let mut visitor = PropsVisitor { props: Vec::new() };
+    let mut visitor = PropsVisitor {
+        source: typescript_code,
+        start_pos: fm.start_pos,
+        comments: &comments,
+        types: HashMap::new(),
+        defaults: HashMap::new(),
+        declared_type: Vec::new(),
+        runtime_props: Vec::new(),
+        components: Vec::new(),
+        default_export: None,
+    };
 
     module.visit_with(&mut visitor);
 
-    Ok(visitor.props)
+    let props_type = || vec![PropsType::Named("Props".to_string())];
+    let component = (framework == ComponentFramework::React)
+        .then(|| visitor.component(component_name))
+        .flatten();
+
+    let (parts, defaults) = match framework {
+        ComponentFramework::Astro => (props_type(), visitor.defaults.clone()),
+        ComponentFramework::React => component
+            .map(|component| (component.props_type.clone(), component.defaults.clone()))
+            .unwrap_or_else(|| (props_type(), HashMap::new())),
+        ComponentFramework::Vue | ComponentFramework::Svelte
+            if !visitor.declared_type.is_empty() =>
+        {
+            (visitor.declared_type.clone(), visitor.defaults.clone())
+        }
+        ComponentFramework::Vue | ComponentFramework::Svelte
+            if !visitor.runtime_props.is_empty() =>
+        {
+            (Vec::new(), visitor.defaults.clone())
+        }
+        ComponentFramework::Vue | ComponentFramework::Svelte => {
+            (props_type(), visitor.defaults.clone())
+        }
+    };
+
+    let mut props = visitor.resolve(&parts);
+    if parts.is_empty() {
+        props = visitor.runtime_props.clone();
+    }
+    for prop in &mut props {
+        if let Some(default) = defaults.get(&prop.name) {
+            prop.default_value = Some(default.clone());
+        }
+    }
+
+    let has_children = matches!(
+        framework,
+        ComponentFramework::React | ComponentFramework::Svelte
+    ) && props.iter().any(|prop| prop.name == "children");
+    if has_children {
+        props.retain(|prop| prop.name != "children");
+    }
+
+    // JSDoc on the component function, then on its props type, then at the top of the
+    // script. `export interface Props` has its comment before `export`.
+    let item_start = |pos: BytePos| {
+        module
+            .body
+            .iter()
+            .find(|item| item.span().lo <= pos && pos < item.span().hi)
+            .map(|item| item.span().lo)
+    };
+    let mut doc_positions: Vec<BytePos> = Vec::new();
+    doc_positions.extend(component.and_then(|component| item_start(component.start)));
+    doc_positions.extend(parts.iter().find_map(|part| {
+        match part {
+            PropsType::Named(name) => visitor
+                .types
+                .get(name)
+                .and_then(|(_, span)| item_start(span.lo)),
+            PropsType::Inline(_) => None,
+        }
+    }));
+    doc_positions.extend(module.body.first().map(|item| item.span().lo));
+    let docs: Vec<JsDoc> = doc_positions
+        .into_iter()
+        .filter_map(|pos| jsdoc(&comments, pos))
+        .collect();
+
+    Ok(ComponentScript {
+        props,
+        description: docs
+            .iter()
+            .map(|doc| doc.description.clone())
+            .find(|description| !description.is_empty()),
+        has_children,
+        client_directive: docs.into_iter().find_map(|doc| doc.client),
+    })
 }
 
-struct PropsVisitor {
-    props: Vec<PropInfo>,
+/// Where a component's props are declared
+#[derive(Clone)]
+enum PropsType {
+    Named(String),         // An interface or type alias
+    Inline(Vec<PropInfo>), // A type literal
 }
 
-impl Visit for PropsVisitor {
+/// A function that may be a React component
+struct FunctionComponent {
+    name: Option<String>,
+    is_default_export: bool,
+    props_type: Vec<PropsType>,
+    defaults: HashMap<String, String>,
+    start: BytePos,
+}
+
+struct PropsVisitor<'a> {
+    source: &'a str,
+    start_pos: BytePos,
+    comments: &'a SingleThreadedComments,
+    types: HashMap<String, (Vec<PropInfo>, Span)>, // Interfaces and type literal aliases
+    defaults: HashMap<String, String>, // From destructuring `Astro.props`, `$props()` or `defineProps()`
+    declared_type: Vec<PropsType>,     // The type of `$props()` or `defineProps<...>()`
+    runtime_props: Vec<PropInfo>,      // Svelte `export let` or Vue runtime `defineProps`
+    components: Vec<FunctionComponent>,
+    default_export: Option<String>,
+}
+
+impl PropsVisitor<'_> {
+    fn members(&self, members: &[TsTypeElement]) -> Vec<PropInfo> {
+        members
+            .iter()
+            .filter_map(|member| match member {
+                TsTypeElement::TsPropertySignature(prop) => extract_prop_info(prop, self.comments),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn snippet(&self, span: Span) -> Option<String> {
+        let start = (span.lo.0.checked_sub(self.start_pos.0)?) as usize;
+        let end = (span.hi.0.checked_sub(self.start_pos.0)?) as usize;
+        self.source.get(start..end).map(str::to_string)
+    }
+
+    /// The parts of a props type; `Props & HTMLAttributes<'div'>` has two
+    fn props_type(&self, ts_type: &TsType) -> Vec<PropsType> {
+        match ts_type {
+            TsType::TsTypeRef(type_ref) => match &type_ref.type_name {
+                TsEntityName::Ident(ident) => vec![PropsType::Named(ident.sym.to_string())],
+                _ => Vec::new(),
+            },
+            TsType::TsTypeLit(literal) => vec![PropsType::Inline(self.members(&literal.members))],
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+                intersection,
+            )) => intersection
+                .types
+                .iter()
+                .flat_map(|part| self.props_type(part))
+                .collect(),
+            TsType::TsParenthesizedType(paren) => self.props_type(&paren.type_ann),
+            _ => Vec::new(),
+        }
+    }
+
+    fn resolve(&self, parts: &[PropsType]) -> Vec<PropInfo> {
+        parts
+            .iter()
+            .flat_map(|part| match part {
+                PropsType::Named(name) => self
+                    .types
+                    .get(name)
+                    .map(|(props, _)| props.clone())
+                    .unwrap_or_default(),
+                PropsType::Inline(props) => props.clone(),
+            })
+            .collect()
+    }
+
+    /// `{ type = 'info' }` and `{ type: kind = 'info' }`
+    fn pattern_defaults(&self, pattern: &ObjectPat) -> HashMap<String, String> {
+        let mut defaults = HashMap::new();
+        for prop in &pattern.props {
+            let (name, default) = match prop {
+                ObjectPatProp::Assign(assign) => {
+                    (assign.key.sym.to_string(), assign.value.as_ref())
+                }
+                ObjectPatProp::KeyValue(key_value) => {
+                    let PropName::Ident(key) = &key_value.key else {
+                        continue;
+                    };
+                    let Pat::Assign(assign) = key_value.value.as_ref() else {
+                        continue;
+                    };
+                    (key.sym.to_string(), Some(&assign.right))
+                }
+                ObjectPatProp::Rest(_) => continue,
+            };
+            if let Some(default) = default.and_then(|expr| self.snippet(expr.span())) {
+                defaults.insert(name, default);
+            }
+        }
+        defaults
+    }
+
+    /// Records a function whose first parameter holds the props
+    fn add_component(
+        &mut self,
+        name: Option<String>,
+        is_default_export: bool,
+        first_param: Option<&Pat>,
+        mut props_type: Vec<PropsType>,
+        start: BytePos,
+    ) {
+        let mut defaults = HashMap::new();
+        let mut param = first_param;
+        while let Some(Pat::Assign(assign)) = param {
+            param = Some(&assign.left);
+        }
+        match param {
+            Some(Pat::Object(pattern)) => {
+                defaults = self.pattern_defaults(pattern);
+                if let Some(type_ann) = &pattern.type_ann {
+                    props_type = self.props_type(&type_ann.type_ann);
+                }
+            }
+            Some(Pat::Ident(ident)) => {
+                if let Some(type_ann) = &ident.type_ann {
+                    props_type = self.props_type(&type_ann.type_ann);
+                }
+            }
+            _ => {}
+        }
+
+        self.components.push(FunctionComponent {
+            name,
+            is_default_export,
+            props_type,
+            defaults,
+            start,
+        });
+    }
+
+    /// The component named after the file, else the default export, else the first one
+    fn component(&self, component_name: &str) -> Option<&FunctionComponent> {
+        self.components
+            .iter()
+            .find(|component| component.name.as_deref() == Some(component_name))
+            .or_else(|| {
+                self.components.iter().find(|component| {
+                    component.is_default_export
+                        || component.name.is_some() && component.name == self.default_export
+                })
+            })
+            .or_else(|| self.components.first())
+    }
+
+    /// Vue `defineProps({ title: String, size: { type: Number, default: 2 } })` or
+    /// `defineProps(['title'])`
+    fn add_runtime_props(&mut self, declaration: &Expr) {
+        match declaration {
+            Expr::Array(array) => {
+                for element in array.elems.iter().flatten() {
+                    if let Expr::Lit(Lit::Str(name)) = element.expr.as_ref() {
+                        self.runtime_props.push(PropInfo {
+                            name: format!("{}", name.value),
+                            prop_type: "unknown".to_string(),
+                            is_optional: true,
+                            default_value: None,
+                            description: None,
+                        });
+                    }
+                }
+            }
+            Expr::Object(object) => {
+                for prop in &object.props {
+                    let PropOrSpread::Prop(prop) = prop else {
+                        continue;
+                    };
+                    let Prop::KeyValue(key_value) = prop.as_ref() else {
+                        continue;
+                    };
+                    let Some(name) = prop_name(&key_value.key) else {
+                        continue;
+                    };
+
+                    let mut prop_info = PropInfo {
+                        name,
+                        prop_type: runtime_type(&key_value.value),
+                        is_optional: true,
+                        default_value: None,
+                        description: jsdoc(self.comments, key_value.key.span().lo)
+                            .map(|doc| doc.description)
+                            .filter(|description| !description.is_empty()),
+                    };
+                    if let Expr::Object(options) = key_value.value.as_ref() {
+                        for (option, value) in object_entries(options) {
+                            match option.as_str() {
+                                "type" => prop_info.prop_type = runtime_type(value),
+                                "required" => {
+                                    prop_info.is_optional =
+                                        !matches!(value, Expr::Lit(Lit::Bool(b)) if b.value)
+                                }
+                                "default" => prop_info.default_value = self.snippet(value.span()),
+                                _ => {}
+                            }
+                        }
+                    }
+                    self.runtime_props.push(prop_info);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Visit for PropsVisitor<'_> {
     fn visit_ts_interface_decl(&mut self, node: &TsInterfaceDecl) {
-        // Look for interface named "Props"
-        if node.id.sym.as_str() == "Props" {
-            for member in &node.body.body {
-                if let TsTypeElement::TsPropertySignature(prop) = member {
-                    if let Some(prop_info) = extract_prop_info(prop) {
-                        self.props.push(prop_info);
+        let props = self.members(&node.body.body);
+        self.types
+            .insert(node.id.sym.to_string(), (props, node.span));
+    }
+
+    fn visit_ts_type_alias_decl(&mut self, node: &TsTypeAliasDecl) {
+        // `type Props = { ... }`
+        if let TsType::TsTypeLit(literal) = node.type_ann.as_ref() {
+            let props = self.members(&literal.members);
+            self.types
+                .insert(node.id.sym.to_string(), (props, node.span));
+        }
+    }
+
+    fn visit_var_declarator(&mut self, node: &VarDeclarator) {
+        node.visit_children_with(self);
+        let Some(init) = &node.init else {
+            return;
+        };
+
+        match &node.name {
+            // `const { type = 'info' } = Astro.props`
+            Pat::Object(pattern) if is_props_source(init) => {
+                self.defaults.extend(self.pattern_defaults(pattern));
+                if let Some(type_ann) = &pattern.type_ann {
+                    self.declared_type = self.props_type(&type_ann.type_ann);
+                }
+            }
+            // `const Counter = ({ start = 0 }: Props) => ...` or `const Counter: FC<Props> = ...`
+            Pat::Ident(binding) if binding.id.sym.starts_with(char::is_uppercase) => {
+                let first_param = match init.as_ref() {
+                    Expr::Arrow(arrow) => arrow.params.first(),
+                    Expr::Fn(function) => function.function.params.first().map(|param| &param.pat),
+                    _ => return,
+                };
+                let fc_type = binding
+                    .type_ann
+                    .as_ref()
+                    .and_then(|type_ann| function_component_props(&type_ann.type_ann))
+                    .map(|props| self.props_type(props))
+                    .unwrap_or_default();
+                self.add_component(
+                    Some(binding.id.sym.to_string()),
+                    false,
+                    first_param,
+                    fc_type,
+                    node.span.lo,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_fn_decl(&mut self, node: &FnDecl) {
+        node.visit_children_with(self);
+        if node.ident.sym.starts_with(char::is_uppercase) {
+            self.add_component(
+                Some(node.ident.sym.to_string()),
+                false,
+                node.function.params.first().map(|param| &param.pat),
+                Vec::new(),
+                node.function.span.lo,
+            );
+        }
+    }
+
+    fn visit_export_default_decl(&mut self, node: &ExportDefaultDecl) {
+        node.visit_children_with(self);
+        if let DefaultDecl::Fn(function) = &node.decl {
+            self.add_component(
+                function.ident.as_ref().map(|ident| ident.sym.to_string()),
+                true,
+                function.function.params.first().map(|param| &param.pat),
+                Vec::new(),
+                node.span.lo,
+            );
+        }
+    }
+
+    fn visit_export_default_expr(&mut self, node: &ExportDefaultExpr) {
+        node.visit_children_with(self);
+        match node.expr.as_ref() {
+            Expr::Ident(ident) => self.default_export = Some(ident.sym.to_string()),
+            Expr::Arrow(arrow) => {
+                self.add_component(None, true, arrow.params.first(), Vec::new(), node.span.lo)
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_export_decl(&mut self, node: &ExportDecl) {
+        node.visit_children_with(self);
+
+        // Svelte props: `export let title: string` or `export let size = 2`
+        let Decl::Var(var) = &node.decl else {
+            return;
+        };
+        if var.kind == VarDeclKind::Const {
+            return;
+        }
+        for declarator in &var.decls {
+            let Pat::Ident(binding) = &declarator.name else {
+                continue;
+            };
+            let doc = jsdoc(self.comments, node.span.lo);
+            self.runtime_props.push(PropInfo {
+                name: binding.id.sym.to_string(),
+                prop_type: binding
+                    .type_ann
+                    .as_ref()
+                    .map(|type_ann| type_to_string(&type_ann.type_ann))
+                    .unwrap_or_else(|| "unknown".to_string()),
+                is_optional: declarator.init.is_some(),
+                default_value: declarator
+                    .init
+                    .as_ref()
+                    .and_then(|init| self.snippet(init.span()))
+                    .or_else(|| doc.as_ref().and_then(|doc| doc.default.clone())),
+                description: doc
+                    .map(|doc| doc.description)
+                    .filter(|description| !description.is_empty()),
+            });
+        }
+    }
+
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        node.visit_children_with(self);
+        let Some(callee) = callee_name(node) else {
+            return;
+        };
+
+        match callee {
+            "defineProps" => {
+                if let Some(type_args) = &node.type_args {
+                    if let Some(props) = type_args.params.first() {
+                        self.declared_type = self.props_type(props);
+                    }
+                } else if let Some(declaration) = node.args.first() {
+                    self.add_runtime_props(&declaration.expr);
+                }
+            }
+            // `withDefaults(defineProps<Props>(), { size: 2 })`
+            "withDefaults" => {
+                if let Some(Expr::Object(defaults)) = node.args.get(1).map(|arg| arg.expr.as_ref())
+                {
+                    for (name, value) in object_entries(defaults) {
+                        if let Some(default) = self.snippet(value.span()) {
+                            self.defaults.insert(name, default);
+                        }
                     }
                 }
             }
+            _ => {}
         }
     }
 }
 
-fn extract_prop_info(prop: &TsPropertySignature) -> Option<PropInfo> {
+/// `Astro.props`, Svelte's `$props()` or Vue's `defineProps()`, also with `as Props` or
+/// `satisfies Props`
+fn is_props_source(expr: &Expr) -> bool {
+    match expr {
+        Expr::Member(member) => {
+            matches!(member.obj.as_ref(), Expr::Ident(obj) if obj.sym.as_str() == "Astro")
+                && matches!(&member.prop, MemberProp::Ident(prop) if prop.sym.as_str() == "props")
+        }
+        Expr::Call(call) => matches!(
+            callee_name(call),
+            Some("$props" | "defineProps" | "withDefaults")
+        ),
+        Expr::TsAs(as_expr) => is_props_source(&as_expr.expr),
+        Expr::TsSatisfies(satisfies) => is_props_source(&satisfies.expr),
+        Expr::Paren(paren) => is_props_source(&paren.expr),
+        _ => false,
+    }
+}
+
+fn callee_name(call: &CallExpr) -> Option<&str> {
+    match &call.callee {
+        Callee::Expr(callee) => match callee.as_ref() {
+            Expr::Ident(ident) => Some(ident.sym.as_str()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The props type of `FC<Props>`, `React.FC<Props>` or `FunctionComponent<Props>`
+fn function_component_props(ts_type: &TsType) -> Option<&TsType> {
+    let TsType::TsTypeRef(type_ref) = ts_type else {
+        return None;
+    };
+    let name = match &type_ref.type_name {
+        TsEntityName::Ident(ident) => ident.sym.as_str(),
+        TsEntityName::TsQualifiedName(qualified) => qualified.right.sym.as_str(),
+    };
+    if !matches!(name, "FC" | "FunctionComponent" | "VFC") {
+        return None;
+    }
+    type_ref
+        .type_params
+        .as_ref()?
+        .params
+        .first()
+        .map(|props| props.as_ref())
+}
+
+fn prop_name(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(s) => Some(format!("{}", s.value)),
+        _ => None,
+    }
+}
+
+/// The keys and values of an object literal
+fn object_entries(object: &ObjectLit) -> Vec<(String, &Expr)> {
+    object
+        .props
+        .iter()
+        .filter_map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match prop.as_ref() {
+                Prop::KeyValue(key_value) => {
+                    Some((prop_name(&key_value.key)?, key_value.value.as_ref()))
+                }
+                _ => None,
+            },
+            PropOrSpread::Spread(_) => None,
+        })
+        .collect()
+}
+
+/// The TypeScript type of a Vue runtime prop type: `String`, `[String, Number]` or
+/// `{ type: Boolean }`
+fn runtime_type(expr: &Expr) -> String {
+    match expr {
+        Expr::Ident(ident) => match ident.sym.as_str() {
+            "String" | "Number" | "Boolean" | "Object" => ident.sym.to_lowercase(),
+            "Array" => "unknown[]".to_string(),
+            other => other.to_string(),
+        },
+        Expr::Array(types) => types
+            .elems
+            .iter()
+            .flatten()
+            .map(|element| runtime_type(&element.expr))
+            .collect::<Vec<_>>()
+            .join(" | "),
+        Expr::Object(options) => object_entries(options)
+            .into_iter()
+            .find(|(option, _)| option == "type")
+            .map_or_else(|| "unknown".to_string(), |(_, value)| runtime_type(value)),
+        Expr::TsAs(as_expr) => runtime_type(&as_expr.expr),
+        _ => "unknown".to_string(),
+    }
+}
+
+/// A JSDoc comment: its text up to the first tag, and the `@default` and `@client` tags
+struct JsDoc {
+    description: String,
+    default: Option<String>,
+    client: Option<String>, // `@client visible` or `@client client:visible`
+}
+
+/// The JSDoc (`/** ... */`) comment directly before `pos`
+fn jsdoc(comments: &SingleThreadedComments, pos: BytePos) -> Option<JsDoc> {
+    let comment = comments
+        .get_leading(pos)?
+        .into_iter()
+        .rev()
+        .find(|comment| comment.kind == CommentKind::Block && comment.text.starts_with('*'))?;
+
+    let lines: Vec<&str> = comment.text[1..]
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .collect();
+    let description = lines
+        .iter()
+        .take_while(|line| !line.starts_with('@'))
+        .filter(|line| !line.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let tag = |name: &str| {
+        lines.iter().find_map(|line| {
+            let value = line.strip_prefix(name)?;
+            // `@default` shouldn't match `@defaults`
+            if !value.is_empty() && !value.starts_with(char::is_whitespace) {
+                return None;
+            }
+            Some(value.trim().to_string()).filter(|value| !value.is_empty())
+        })
+    };
+
+    Some(JsDoc {
+        description,
+        default: tag("@default").or_else(|| tag("@defaultValue")),
+        client: tag("@client").map(|directive| {
+            if directive.starts_with("client:") {
+                directive
+            } else {
+                format!("client:{directive}")
+            }
+        }),
+    })
+}
+
+fn extract_prop_info(
+    prop: &TsPropertySignature,
+    comments: &SingleThreadedComments,
+) -> Option<PropInfo> {
     // Extract property name
     let name = match prop.key.as_ref() {
         Expr::Ident(ident) => ident.sym.to_string(),
@@ -207,11 +914,16 @@ fn extract_prop_info(prop: &TsPropertySignature) -> Option<PropInfo> {
         "unknown".to_string()
     };
 
+    let doc = jsdoc(comments, prop.span.lo);
+
     Some(PropInfo {
         name,
         prop_type,
         is_optional,
-        default_value: None, // TODO: Extract default values from AST
+        default_value: doc.as_ref().and_then(|doc| doc.default.clone()),
+        description: doc
+            .map(|doc| doc.description)
+            .filter(|description| !description.is_empty()),
     })
 }
 
@@ -324,7 +1036,9 @@ mod tests {
             }
         "#;
 
-        let props = parse_props_from_typescript(typescript_code).unwrap();
+        let props = parse_component_script(typescript_code, ComponentFramework::Astro, "Alert")
+            .unwrap()
+            .props;
 
         assert_eq!(props.len(), 4);
 
@@ -345,6 +1059,52 @@ mod tests {
         assert!(props[3].is_optional);
     }
 
+    #[test]
+    fn test_parse_component_script_defaults_and_docs() {
+        let typescript_code = r#"
+            import Icon from './Icon.astro';
+
+            /**
+             * Highlights a note, tip or warning.
+             *
+             * @example <Callout type="tip">Hi</Callout>
+             */
+            export type Props = {
+                /** Visual style of the callout */
+                type?: 'info' | 'tip' | 'warning';
+                title?: string;
+                /**
+                 * Starts collapsed
+                 * @default false
+                 */
+                collapsed?: boolean;
+                size?: number;
+            };
+
+            const { type = 'info', title: heading = "Note", size = 2, ...rest } = Astro.props as Props;
+        "#;
+
+        let script =
+            parse_component_script(typescript_code, ComponentFramework::Astro, "Callout").unwrap();
+        assert_eq!(
+            script.description.as_deref(),
+            Some("Highlights a note, tip or warning.")
+        );
+
+        let props = script.props;
+        assert_eq!(props.len(), 4);
+        assert_eq!(props[0].default_value.as_deref(), Some("'info'"));
+        assert_eq!(
+            props[0].description.as_deref(),
+            Some("Visual style of the callout")
+        );
+        assert_eq!(props[1].default_value.as_deref(), Some("\"Note\""));
+        assert_eq!(props[1].description, None);
+        assert_eq!(props[2].default_value.as_deref(), Some("false"));
+        assert_eq!(props[2].description.as_deref(), Some("Starts collapsed"));
+        assert_eq!(props[3].default_value.as_deref(), Some("2"));
+    }
+
     #[tokio::test]
     async fn test_scan_mdx_components() {
         let temp_dir = TempDir::new().unwrap();
@@ -368,6 +1128,7 @@ interface Props {
         let components = scan_mdx_components(
             temp_dir.path().to_str().unwrap().to_string(),
             Some("src/components/mdx".to_string()),
+            None,
         )
         .await
         .unwrap();
@@ -378,6 +1139,175 @@ interface Props {
         assert!(components[0].has_slot);
     }
 
+    #[tokio::test]
+    async fn test_scan_framework_components() {
+        let temp_dir = TempDir::new().unwrap();
+        let mdx_dir = temp_dir.path().join("src/components/mdx");
+        fs::create_dir_all(&mdx_dir).unwrap();
+
+        fs::write(
+            mdx_dir.join("Counter.tsx"),
+            r#"import { useState } from 'react';
+import type { ReactNode } from 'react';
+
+interface CounterProps {
+  /** Where counting starts */
+  start?: number;
+  label: string;
+  children?: ReactNode;
+}
+
+function format(value: number) {
+  return value.toString();
+}
+
+/** A button that counts clicks */
+export default function Counter({ start = 0, label, children }: CounterProps) {
+  const [count, setCount] = useState(start);
+  return <button onClick={() => setCount(count + 1)}>{label} {format(count)} {children}</button>;
+}
+"#,
+        )
+        .unwrap();
+        fs::write(
+            mdx_dir.join("Badge.tsx"),
+            r#"/**
+ * A static label
+ * @client visible
+ */
+export const Badge: React.FC<{ text: string; tone?: 'info' | 'warning' }> = ({ text, tone = 'info' }) => (
+  <span className={tone}>{text}</span>
+);
+"#,
+        )
+        .unwrap();
+        fs::write(
+            mdx_dir.join("Toggle.svelte"),
+            r#"<script lang="ts">
+  /** Label next to the switch */
+  export let label: string;
+  export let checked = false;
+</script>
+
+<label><input type="checkbox" bind:checked on:change={() => {}} /> {label}</label>
+"#,
+        )
+        .unwrap();
+        fs::write(
+            mdx_dir.join("Tabs.svelte"),
+            r#"<script lang="ts">
+  type Props = { tabs: string[]; active?: number; children?: any };
+  let { tabs, active = 0, children }: Props = $props();
+  let current = $state(active);
+</script>
+
+{@render children?.()}
+"#,
+        )
+        .unwrap();
+        fs::write(
+            mdx_dir.join("Chart.vue"),
+            r#"<script setup lang="ts">
+import { ref } from 'vue';
+
+interface Props {
+  data: number[];
+  kind?: 'bar' | 'line';
+}
+
+const props = withDefaults(defineProps<Props>(), { kind: 'bar' });
+const hovered = ref(null);
+</script>
+
+<template><div @mouseover="hovered = 1"><slot /></div></template>
+"#,
+        )
+        .unwrap();
+        fs::write(
+            mdx_dir.join("Note.vue"),
+            r#"<script>
+export default {};
+</script>
+<script setup>
+defineProps({ title: String, level: { type: Number, default: 1, required: true } });
+</script>
+
+<template><aside>{{ title }}</aside></template>
+"#,
+        )
+        .unwrap();
+
+        let mut components = scan_mdx_components(
+            temp_dir.path().to_str().unwrap().to_string(),
+            Some("src/components/mdx".to_string()),
+            None,
+        )
+        .await
+        .unwrap();
+        components.sort_by(|a, b| a.name.cmp(&b.name));
+        let names: Vec<&str> = components.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["Badge", "Chart", "Counter", "Note", "Tabs", "Toggle"]
+        );
+
+        let badge = &components[0];
+        assert_eq!(badge.framework, ComponentFramework::React);
+        assert_eq!(badge.client_directive.as_deref(), Some("client:visible"));
+        assert_eq!(badge.description.as_deref(), Some("A static label"));
+        assert_eq!(badge.props.len(), 2);
+        assert_eq!(badge.props[1].default_value.as_deref(), Some("'info'"));
+
+        let chart = &components[1];
+        assert_eq!(chart.framework, ComponentFramework::Vue);
+        assert_eq!(chart.client_directive.as_deref(), Some("client:load"));
+        assert!(chart.has_slot);
+        assert_eq!(chart.props[0].prop_type, "number[]");
+        assert_eq!(chart.props[1].default_value.as_deref(), Some("'bar'"));
+
+        let counter = &components[2];
+        assert_eq!(counter.client_directive.as_deref(), Some("client:load"));
+        assert_eq!(
+            counter.description.as_deref(),
+            Some("A button that counts clicks")
+        );
+        assert!(counter.has_slot);
+        let props: Vec<&str> = counter.props.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(props, vec!["start", "label"]);
+        assert_eq!(counter.props[0].default_value.as_deref(), Some("0"));
+        assert_eq!(
+            counter.props[0].description.as_deref(),
+            Some("Where counting starts")
+        );
+
+        let note = &components[3];
+        assert_eq!(note.client_directive, None);
+        assert_eq!(note.props[0].prop_type, "string");
+        assert!(note.props[0].is_optional);
+        assert_eq!(note.props[1].prop_type, "number");
+        assert!(!note.props[1].is_optional);
+        assert_eq!(note.props[1].default_value.as_deref(), Some("1"));
+
+        let tabs = &components[4];
+        assert_eq!(tabs.framework, ComponentFramework::Svelte);
+        assert!(tabs.has_slot);
+        assert_eq!(tabs.props.len(), 2);
+        assert_eq!(tabs.props[1].default_value.as_deref(), Some("0"));
+        assert_eq!(tabs.client_directive.as_deref(), Some("client:load"));
+
+        let toggle = &components[5];
+        assert_eq!(toggle.props[0].name, "label");
+        assert_eq!(toggle.props[0].prop_type, "string");
+        assert!(!toggle.props[0].is_optional);
+        assert_eq!(
+            toggle.props[0].description.as_deref(),
+            Some("Label next to the switch")
+        );
+        assert_eq!(toggle.props[1].default_value.as_deref(), Some("false"));
+        assert!(toggle.props[1].is_optional);
+        assert!(!toggle.has_slot);
+    }
+
     #[tokio::test]
     async fn test_scan_mdx_components_path_traversal_protection() {
         let temp_dir = TempDir::new().unwrap();
@@ -388,6 +1318,7 @@ interface Props {
         let result = scan_mdx_components(
             project_root.to_str().unwrap().to_string(),
             Some("../../../etc".to_string()),
+            None,
         )
         .await;
 
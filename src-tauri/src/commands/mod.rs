@@ -1,15 +1,35 @@
+pub mod assets;
 pub mod clipboard;
 pub mod diagnostics;
 pub mod files;
 pub mod ide;
+pub mod images;
+pub mod links;
 pub mod mdx_components;
 pub mod project;
+pub mod schema_migration;
+pub mod settings;
+pub mod stats;
+pub mod taxonomy;
+pub mod translations;
 pub mod watcher;
+pub mod windows;
+pub mod workspace;
 
+pub use assets::*;
 pub use clipboard::*;
 pub use diagnostics::*;
 pub use files::*;
 pub use ide::*;
+pub use images::*;
+pub use links::*;
 pub use mdx_components::*;
 pub use project::*;
+pub use schema_migration::*;
+pub use settings::*;
+pub use stats::*;
+pub use taxonomy::*;
+pub use translations::*;
 pub use watcher::*;
+pub use windows::*;
+pub use workspace::*;
@@ -1,5 +1,8 @@
-use crate::models::{Collection, FileEntry};
+use crate::astro_config::load_astro_project_config;
+use crate::commands::translations::{link_translations, LocaleLayout};
+use crate::models::{AstroProjectConfig, Collection, FileEntry};
 use crate::parser::parse_astro_config;
+use crate::project_ignore::{find_project_root, project_walker};
 use log::{debug, error, info, warn};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -32,7 +35,7 @@ fn send_toast_notification(
 }
 
 /// Check if a directory path is in the blocked/dangerous list
-fn is_blocked_directory(path: &Path) -> bool {
+pub(crate) fn is_blocked_directory(path: &Path) -> bool {
     let path_str = path.to_string_lossy();
 
     // List of blocked directory patterns (matching our Tauri capabilities deny list)
@@ -111,6 +114,17 @@ pub async fn select_project_folder(app: tauri::AppHandle) -> Result<Option<Strin
     }
 }
 
+/// Reads srcDir, publicDir, base, site, trailingSlash, i18n and integrations from the
+/// project's astro.config
+#[tauri::command]
+pub async fn get_astro_project_config(project_path: String) -> Result<AstroProjectConfig, String> {
+    let path = Path::new(&project_path);
+    if !path.is_dir() {
+        return Err(format!("Project directory not found: {project_path}"));
+    }
+    Ok(load_astro_project_config(path))
+}
+
 #[tauri::command]
 pub async fn scan_project(project_path: String) -> Result<Vec<Collection>, String> {
     info!("Astro Editor [PROJECT_SCAN] Scanning project at path: {project_path}");
@@ -123,13 +137,13 @@ pub async fn scan_project_with_content_dir(
     content_directory: Option<String>,
 ) -> Result<Vec<Collection>, String> {
     info!("Astro Editor [PROJECT_SCAN] Scanning project at path: {project_path}");
-    info!(
-        "Astro Editor [PROJECT_SCAN] Content directory: {:?}",
-        content_directory.as_deref().unwrap_or("src/content")
-    );
-
     let path = PathBuf::from(&project_path);
 
+    // Without an override, the content directory follows srcDir from astro.config
+    let content_directory =
+        content_directory.unwrap_or_else(|| load_astro_project_config(&path).content_directory());
+    info!("Astro Editor [PROJECT_SCAN] Content directory: {content_directory}");
+
     // Try to parse Astro config first
     debug!("Astro Editor [PROJECT_SCAN] Attempting to parse Astro config");
     match parse_astro_config(&path) {
@@ -142,29 +156,21 @@ pub async fn scan_project_with_content_dir(
         }
         Ok(_) => {
             debug!("Astro Editor [PROJECT_SCAN] Astro config returned empty collections, falling back to directory scan");
-            scan_content_directories_with_override(path.as_path(), content_directory)
+            scan_content_directories(path.as_path(), content_directory)
         }
         Err(err) => {
             debug!("Astro Editor [PROJECT_SCAN] Astro config parsing failed: {err}, falling back to directory scan");
-            scan_content_directories_with_override(path.as_path(), content_directory)
+            scan_content_directories(path.as_path(), content_directory)
         }
     }
 }
 
-fn scan_content_directories_with_override(
+fn scan_content_directories(
     project_path: &Path,
-    content_directory_override: Option<String>,
+    content_directory: String,
 ) -> Result<Vec<Collection>, String> {
     let mut collections = Vec::new();
-
-    // Use override if provided, otherwise default to src/content
-    let content_dir = if let Some(override_path) = &content_directory_override {
-        debug!("Astro Editor [PROJECT_SCAN] Using content directory override: {override_path}");
-        project_path.join(override_path)
-    } else {
-        debug!("Astro Editor [PROJECT_SCAN] Using default content directory: src/content");
-        project_path.join("src").join("content")
-    };
+    let content_dir = project_path.join(content_directory);
 
     if content_dir.exists() {
         info!(
@@ -208,10 +214,20 @@ fn scan_content_directories_with_override(
 }
 
 #[tauri::command]
-pub async fn scan_collection_files(collection_path: String) -> Result<Vec<FileEntry>, String> {
+pub async fn scan_collection_files(
+    collection_path: String,
+    ignore_patterns: Option<Vec<String>>,
+) -> Result<Vec<FileEntry>, String> {
     let path = PathBuf::from(&collection_path);
     let mut files = Vec::new();
 
+    if !path.is_dir() {
+        return Err(format!(
+            "Failed to read collection directory: {collection_path} is not a directory"
+        ));
+    }
+    let project_root = find_project_root(&path);
+
     // Get collection name from path
     let collection_name = path
         .file_name()
@@ -219,12 +235,35 @@ pub async fn scan_collection_files(collection_path: String) -> Result<Vec<FileEn
         .unwrap_or("unknown")
         .to_string();
 
-    // Scan for markdown and MDX files
-    for entry in
-        std::fs::read_dir(&path).map_err(|e| format!("Failed to read collection directory: {e}"))?
-    {
+    // Entries in locale folders (`docs/en/...`) are nested, otherwise only the top level counts
+    let locale_layout = LocaleLayout::detect(&path, &project_root);
+    let max_depth = if locale_layout.has_locale_dirs() {
+        None
+    } else {
+        Some(1)
+    };
+
+    // Scan for markdown and MDX files, skipping anything ignored
+    let walker = project_walker(
+        &path,
+        &project_root,
+        ignore_patterns.as_deref().unwrap_or_default(),
+    )
+    .max_depth(max_depth)
+    .build();
+
+    for entry in walker {
         let entry = entry.map_err(|e| format!("Failed to read directory entry: {e}"))?;
-        let path = entry.path();
+        let relative_path = entry.path().strip_prefix(&path).unwrap_or(entry.path());
+        let in_locale_dir = relative_path
+            .components()
+            .next()
+            .is_some_and(|dir| locale_layout.is_locale_dir(&dir.as_os_str().to_string_lossy()));
+        if entry.depth() > 1 && !in_locale_dir {
+            continue;
+        }
+        let relative_path = relative_path.to_path_buf();
+        let path = entry.path().to_path_buf();
 
         if path.is_file() {
             if let Some(extension) = path.extension().and_then(|ext| ext.to_str()) {
@@ -240,11 +279,19 @@ pub async fn scan_collection_files(collection_path: String) -> Result<Vec<FileEn
                         }
                     }
 
+                    if let Some((locale, key, relative_id)) =
+                        locale_layout.file_locale(&relative_path, file_entry.frontmatter.as_ref())
+                    {
+                        file_entry = file_entry.with_translation(locale, key, &relative_id);
+                    }
+
                     files.push(file_entry);
                 }
             }
         }
     }
 
+    link_translations(&mut files, &locale_layout);
+
     Ok(files)
 }
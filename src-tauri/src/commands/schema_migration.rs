@@ -0,0 +1,721 @@
+use crate::commands::files::{update_frontmatter_bulk, BulkFrontmatterResult};
+use crate::models::{Collection, FileEntry};
+use crate::parser::parse_astro_config;
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tauri::{path::BaseDirectory, Manager};
+
+/// A single field from a collection schema, read back from the JSON produced by the parser
+#[derive(Debug, Clone, PartialEq)]
+struct SchemaFieldSummary {
+    name: String,
+    field_type: String,
+    array_type: Option<String>,
+    optional: bool,
+    default_value: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SchemaChange {
+    Renamed {
+        from: String,
+        to: String,
+    },
+    Removed {
+        name: String,
+    },
+    AddedRequired {
+        name: String,
+        field_type: String,
+        default_value: Option<String>,
+    },
+    TypeChanged {
+        name: String,
+        from_type: String,
+        to_type: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoerceTarget {
+    String,
+    Number,
+    Boolean,
+    Array,
+    Date,
+}
+
+/// An automatic fix that can be applied to the frontmatter of affected files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FrontmatterMigration {
+    RenameKey { from: String, to: String },
+    RemoveKey { key: String },
+    FillDefault { key: String, value: Value },
+    CoerceType { key: String, to: CoerceTarget },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaChangeImpact {
+    pub change: SchemaChange,
+    pub affected_files: Vec<String>,
+    /// `None` when the change can't be fixed automatically (e.g. a required field with no default)
+    pub suggested_migration: Option<FrontmatterMigration>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionSchemaReport {
+    pub collection: String,
+    pub collection_path: PathBuf,
+    pub changes: Vec<SchemaChangeImpact>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaMigrationReport {
+    /// True when there was no previous schema to compare against, so the current one was stored
+    pub baseline_created: bool,
+    pub collections: Vec<CollectionSchemaReport>,
+}
+
+/// Compares the current content config against the last acknowledged schema snapshot
+#[tauri::command]
+pub async fn check_schema_migrations(
+    app: tauri::AppHandle,
+    project_path: String,
+) -> Result<SchemaMigrationReport, String> {
+    let current = parse_astro_config(Path::new(&project_path))?;
+    let snapshot_path = schema_snapshot_path(&app, &project_path)?;
+
+    let Some(previous) = read_schema_snapshot(&snapshot_path)? else {
+        info!("Astro Editor [SCHEMA_MIGRATION] No schema snapshot found, storing baseline");
+        write_schema_snapshot(&snapshot_path, &current)?;
+        return Ok(SchemaMigrationReport {
+            baseline_created: true,
+            collections: vec![],
+        });
+    };
+
+    let mut collections = Vec::new();
+
+    for collection in &current {
+        let Some(previous_collection) = previous.iter().find(|c| c.name == collection.name) else {
+            continue;
+        };
+
+        let old_fields = schema_fields(previous_collection.schema.as_deref());
+        let new_fields = schema_fields(collection.schema.as_deref());
+        let changes = diff_schema_fields(&old_fields, &new_fields);
+
+        if changes.is_empty() {
+            continue;
+        }
+
+        debug!(
+            "Astro Editor [SCHEMA_MIGRATION] {} schema changes in collection {}",
+            changes.len(),
+            collection.name
+        );
+
+        let files = crate::commands::project::scan_collection_files(
+            collection.path.to_string_lossy().to_string(),
+            None,
+        )
+        .await?;
+
+        collections.push(CollectionSchemaReport {
+            collection: collection.name.clone(),
+            collection_path: collection.path.clone(),
+            changes: changes
+                .into_iter()
+                .map(|change| assess_change(change, &new_fields, &files))
+                .collect(),
+        });
+    }
+
+    Ok(SchemaMigrationReport {
+        baseline_created: false,
+        collections,
+    })
+}
+
+/// Stores the current content config as the baseline for future schema comparisons
+#[tauri::command]
+pub async fn acknowledge_schema_changes(
+    app: tauri::AppHandle,
+    project_path: String,
+) -> Result<(), String> {
+    let current = parse_astro_config(Path::new(&project_path))?;
+    let snapshot_path = schema_snapshot_path(&app, &project_path)?;
+    write_schema_snapshot(&snapshot_path, &current)
+}
+
+#[tauri::command]
+pub async fn apply_schema_migrations(
+    file_paths: Vec<String>,
+    migrations: Vec<FrontmatterMigration>,
+    project_root: String,
+) -> Result<BulkFrontmatterResult, String> {
+    info!(
+        "Astro Editor [SCHEMA_MIGRATION] Applying {} migrations to {} files",
+        migrations.len(),
+        file_paths.len()
+    );
+
+    let result = update_frontmatter_bulk(&file_paths, &project_root, |frontmatter| {
+        let mut changed = false;
+        for migration in &migrations {
+            changed |= apply_migration(frontmatter, migration);
+        }
+        changed
+    });
+
+    for failure in &result.failed {
+        warn!(
+            "Astro Editor [SCHEMA_MIGRATION] Failed to migrate {}: {}",
+            failure.path, failure.error
+        );
+    }
+
+    Ok(result)
+}
+
+fn schema_snapshot_path(app: &tauri::AppHandle, project_path: &str) -> Result<PathBuf, String> {
+    let snapshot_dir = app
+        .path()
+        .resolve("schema-snapshots", BaseDirectory::AppLocalData)
+        .map_err(|e| format!("Failed to resolve schema snapshot directory: {e}"))?;
+
+    Ok(snapshot_dir.join(format!("{}.json", snapshot_file_stem(project_path))))
+}
+
+/// Turns a project path into a filesystem-safe snapshot name
+fn snapshot_file_stem(project_path: &str) -> String {
+    project_path
+        .trim_matches(|c| c == '/' || c == '\\')
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+fn read_schema_snapshot(snapshot_path: &Path) -> Result<Option<Vec<Collection>>, String> {
+    if !snapshot_path.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(snapshot_path)
+        .map_err(|e| format!("Failed to read schema snapshot: {e}"))?;
+
+    match serde_json::from_str(&content) {
+        Ok(collections) => Ok(Some(collections)),
+        Err(e) => {
+            warn!("Astro Editor [SCHEMA_MIGRATION] Ignoring unreadable schema snapshot: {e}");
+            Ok(None)
+        }
+    }
+}
+
+fn write_schema_snapshot(snapshot_path: &Path, collections: &[Collection]) -> Result<(), String> {
+    if let Some(parent) = snapshot_path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create schema snapshot directory: {e}"))?;
+    }
+
+    let content = serde_json::to_string_pretty(collections)
+        .map_err(|e| format!("Failed to serialize schema snapshot: {e}"))?;
+
+    std::fs::write(snapshot_path, content)
+        .map_err(|e| format!("Failed to write schema snapshot: {e}"))
+}
+
+fn schema_fields(schema: Option<&str>) -> Vec<SchemaFieldSummary> {
+    let Some(parsed) = schema.and_then(|s| serde_json::from_str::<Value>(s).ok()) else {
+        return vec![];
+    };
+
+    parsed["fields"]
+        .as_array()
+        .map(|fields| {
+            fields
+                .iter()
+                .filter_map(|field| {
+                    Some(SchemaFieldSummary {
+                        name: field["name"].as_str()?.to_string(),
+                        field_type: field["type"].as_str().unwrap_or("Unknown").to_string(),
+                        array_type: field["arrayType"].as_str().map(|s| s.to_string()),
+                        optional: field["optional"].as_bool().unwrap_or(false),
+                        default_value: field["default"].as_str().map(|s| s.to_string()),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn type_label(field: &SchemaFieldSummary) -> String {
+    match &field.array_type {
+        Some(inner) if field.field_type == "Array" => format!("Array<{inner}>"),
+        _ => field.field_type.clone(),
+    }
+}
+
+fn diff_schema_fields(
+    old_fields: &[SchemaFieldSummary],
+    new_fields: &[SchemaFieldSummary],
+) -> Vec<SchemaChange> {
+    let removed: Vec<&SchemaFieldSummary> = old_fields
+        .iter()
+        .filter(|old| !new_fields.iter().any(|new| new.name == old.name))
+        .collect();
+    let added: Vec<&SchemaFieldSummary> = new_fields
+        .iter()
+        .filter(|new| !old_fields.iter().any(|old| old.name == new.name))
+        .collect();
+
+    let mut changes = Vec::new();
+    let mut renamed_to = Vec::new();
+
+    for old in &removed {
+        // A removal is treated as a rename when exactly one added field has the same type,
+        // and that added field couldn't be paired with any other removed field either
+        let candidates: Vec<&&SchemaFieldSummary> = added
+            .iter()
+            .filter(|new| type_label(new) == type_label(old))
+            .collect();
+        let unambiguous = candidates.len() == 1
+            && removed
+                .iter()
+                .filter(|other| type_label(other) == type_label(candidates[0]))
+                .count()
+                == 1;
+
+        if unambiguous {
+            renamed_to.push(candidates[0].name.clone());
+            changes.push(SchemaChange::Renamed {
+                from: old.name.clone(),
+                to: candidates[0].name.clone(),
+            });
+        } else {
+            changes.push(SchemaChange::Removed {
+                name: old.name.clone(),
+            });
+        }
+    }
+
+    for new in &added {
+        if !new.optional && !renamed_to.contains(&new.name) {
+            changes.push(SchemaChange::AddedRequired {
+                name: new.name.clone(),
+                field_type: type_label(new),
+                default_value: new.default_value.clone(),
+            });
+        }
+    }
+
+    for new in new_fields {
+        if let Some(old) = old_fields.iter().find(|old| old.name == new.name) {
+            let (from_type, to_type) = (type_label(old), type_label(new));
+            if from_type != to_type && old.field_type != "Unknown" && new.field_type != "Unknown" {
+                changes.push(SchemaChange::TypeChanged {
+                    name: new.name.clone(),
+                    from_type,
+                    to_type,
+                });
+            }
+        }
+    }
+
+    changes
+}
+
+fn assess_change(
+    change: SchemaChange,
+    new_fields: &[SchemaFieldSummary],
+    files: &[FileEntry],
+) -> SchemaChangeImpact {
+    let frontmatter_of = |file: &FileEntry| file.frontmatter.clone().unwrap_or_default();
+
+    let (affected, suggested_migration) = match &change {
+        SchemaChange::Renamed { from, to } => (
+            files_matching(files, |file| frontmatter_of(file).contains_key(from)),
+            Some(FrontmatterMigration::RenameKey {
+                from: from.clone(),
+                to: to.clone(),
+            }),
+        ),
+        SchemaChange::Removed { name } => (
+            files_matching(files, |file| frontmatter_of(file).contains_key(name)),
+            Some(FrontmatterMigration::RemoveKey { key: name.clone() }),
+        ),
+        SchemaChange::AddedRequired {
+            name,
+            field_type,
+            default_value,
+        } => (
+            files_matching(files, |file| !frontmatter_of(file).contains_key(name)),
+            default_value
+                .as_ref()
+                .map(|default| FrontmatterMigration::FillDefault {
+                    key: name.clone(),
+                    value: default_to_value(default, field_type),
+                }),
+        ),
+        SchemaChange::TypeChanged { name, .. } => {
+            let new_field = new_fields.iter().find(|f| &f.name == name);
+            (
+                files_matching(files, |file| {
+                    match (frontmatter_of(file).get(name), new_field) {
+                        (Some(value), Some(field)) => !value_matches_type(value, field),
+                        _ => false,
+                    }
+                }),
+                new_field
+                    .and_then(coerce_target_for)
+                    .map(|to| FrontmatterMigration::CoerceType {
+                        key: name.clone(),
+                        to,
+                    }),
+            )
+        }
+    };
+
+    SchemaChangeImpact {
+        change,
+        affected_files: affected,
+        suggested_migration,
+    }
+}
+
+fn files_matching<F>(files: &[FileEntry], predicate: F) -> Vec<String>
+where
+    F: Fn(&FileEntry) -> bool,
+{
+    files
+        .iter()
+        .filter(|file| predicate(file))
+        .map(|file| file.path.to_string_lossy().to_string())
+        .collect()
+}
+
+fn coerce_target_for(field: &SchemaFieldSummary) -> Option<CoerceTarget> {
+    match field.field_type.as_str() {
+        "String" | "Enum" => Some(CoerceTarget::String),
+        "Number" => Some(CoerceTarget::Number),
+        "Boolean" => Some(CoerceTarget::Boolean),
+        "Array" => Some(CoerceTarget::Array),
+        "Date" => Some(CoerceTarget::Date),
+        _ => None,
+    }
+}
+
+fn value_matches_type(value: &Value, field: &SchemaFieldSummary) -> bool {
+    match field.field_type.as_str() {
+        "String" | "Enum" | "Date" => value.is_string(),
+        "Number" => value.is_number(),
+        "Boolean" => value.is_boolean(),
+        "Array" => value.is_array(),
+        _ => true,
+    }
+}
+
+/// Converts a default value as written in the Zod schema into a frontmatter value
+fn default_to_value(default: &str, field_type: &str) -> Value {
+    let value = Value::String(default.to_string());
+    let target = match field_type {
+        "Number" => CoerceTarget::Number,
+        "Boolean" => CoerceTarget::Boolean,
+        _ if field_type.starts_with("Array") => {
+            if default.trim() == "[]" {
+                return Value::Array(vec![]);
+            }
+            CoerceTarget::Array
+        }
+        _ => return value,
+    };
+    coerce_value(&value, target).unwrap_or(value)
+}
+
+fn apply_migration(
+    frontmatter: &mut HashMap<String, Value>,
+    migration: &FrontmatterMigration,
+) -> bool {
+    match migration {
+        FrontmatterMigration::RenameKey { from, to } => {
+            if frontmatter.contains_key(to) {
+                return false;
+            }
+            match frontmatter.remove(from) {
+                Some(value) => {
+                    frontmatter.insert(to.clone(), value);
+                    true
+                }
+                None => false,
+            }
+        }
+        FrontmatterMigration::RemoveKey { key } => frontmatter.remove(key).is_some(),
+        FrontmatterMigration::FillDefault { key, value } => {
+            if frontmatter.contains_key(key) {
+                return false;
+            }
+            frontmatter.insert(key.clone(), value.clone());
+            true
+        }
+        FrontmatterMigration::CoerceType { key, to } => {
+            let Some(current) = frontmatter.get(key) else {
+                return false;
+            };
+            match coerce_value(current, *to) {
+                Some(coerced) if &coerced != current => {
+                    frontmatter.insert(key.clone(), coerced);
+                    true
+                }
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Converts a frontmatter value to the target type, or `None` if it can't be converted safely
+fn coerce_value(value: &Value, target: CoerceTarget) -> Option<Value> {
+    match (target, value) {
+        (CoerceTarget::String, Value::String(_)) => Some(value.clone()),
+        (CoerceTarget::String, Value::Number(n)) => Some(Value::String(n.to_string())),
+        (CoerceTarget::String, Value::Bool(b)) => Some(Value::String(b.to_string())),
+        (CoerceTarget::String, Value::Array(items)) => Some(Value::String(
+            items
+                .iter()
+                .map(|item| match item {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        )),
+        (CoerceTarget::Number, Value::Number(_)) => Some(value.clone()),
+        (CoerceTarget::Number, Value::String(s)) => {
+            let s = s.trim();
+            if let Ok(n) = s.parse::<i64>() {
+                Some(Value::Number(n.into()))
+            } else {
+                s.parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+            }
+        }
+        (CoerceTarget::Number, Value::Bool(b)) => Some(Value::Number(i64::from(*b).into())),
+        (CoerceTarget::Boolean, Value::Bool(_)) => Some(value.clone()),
+        (CoerceTarget::Boolean, Value::String(s)) => match s.trim().to_lowercase().as_str() {
+            "true" | "yes" | "1" => Some(Value::Bool(true)),
+            "false" | "no" | "0" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        (CoerceTarget::Boolean, Value::Number(n)) => n.as_i64().map(|n| Value::Bool(n != 0)),
+        (CoerceTarget::Array, Value::Array(_)) => Some(value.clone()),
+        (CoerceTarget::Array, Value::String(s)) => Some(Value::Array(
+            s.split(',')
+                .map(|item| item.trim())
+                .filter(|item| !item.is_empty())
+                .map(|item| Value::String(item.to_string()))
+                .collect(),
+        )),
+        (CoerceTarget::Array, Value::Null) => Some(Value::Array(vec![])),
+        (CoerceTarget::Array, other) => Some(Value::Array(vec![other.clone()])),
+        (CoerceTarget::Date, Value::String(s)) => {
+            let date_part = s.split('T').next().unwrap_or(s);
+            chrono::NaiveDate::parse_from_str(date_part.trim(), "%Y-%m-%d")
+                .ok()
+                .map(|date| Value::String(date.format("%Y-%m-%d").to_string()))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn field(name: &str, field_type: &str, optional: bool) -> SchemaFieldSummary {
+        SchemaFieldSummary {
+            name: name.to_string(),
+            field_type: field_type.to_string(),
+            array_type: None,
+            optional,
+            default_value: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_detects_rename() {
+        let old = vec![
+            field("title", "String", false),
+            field("pubDate", "Date", false),
+        ];
+        let new = vec![
+            field("title", "String", false),
+            field("publishedAt", "Date", false),
+        ];
+
+        let changes = diff_schema_fields(&old, &new);
+
+        assert_eq!(
+            changes,
+            vec![SchemaChange::Renamed {
+                from: "pubDate".to_string(),
+                to: "publishedAt".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_ambiguous_rename_is_removal() {
+        let old = vec![
+            field("subtitle", "String", true),
+            field("blurb", "String", true),
+        ];
+        let new = vec![field("summary", "String", true)];
+
+        let changes = diff_schema_fields(&old, &new);
+
+        assert_eq!(changes.len(), 2);
+        assert!(changes
+            .iter()
+            .all(|c| matches!(c, SchemaChange::Removed { .. })));
+    }
+
+    #[test]
+    fn test_diff_added_required_and_type_changed() {
+        let old = vec![
+            field("title", "String", false),
+            field("rating", "String", true),
+        ];
+        let mut category = field("category", "Enum", false);
+        category.default_value = Some("news".to_string());
+        let new = vec![
+            field("title", "String", false),
+            field("rating", "Number", true),
+            category,
+            field("notes", "String", true),
+        ];
+
+        let changes = diff_schema_fields(&old, &new);
+
+        assert!(changes.contains(&SchemaChange::AddedRequired {
+            name: "category".to_string(),
+            field_type: "Enum".to_string(),
+            default_value: Some("news".to_string()),
+        }));
+        assert!(changes.contains(&SchemaChange::TypeChanged {
+            name: "rating".to_string(),
+            from_type: "String".to_string(),
+            to_type: "Number".to_string(),
+        }));
+        // Optional additions don't need migrating
+        assert_eq!(changes.len(), 2);
+    }
+
+    #[test]
+    fn test_schema_fields_from_parser_json() {
+        let schema = r#"{"type":"zod","fields":[
+            {"name":"tags","type":"Array","arrayType":"String","optional":false,"default":null},
+            {"name":"draft","type":"Boolean","optional":true,"default":"false"}
+        ]}"#;
+
+        let fields = schema_fields(Some(schema));
+
+        assert_eq!(fields.len(), 2);
+        assert_eq!(type_label(&fields[0]), "Array<String>");
+        assert_eq!(fields[1].default_value.as_deref(), Some("false"));
+        assert!(schema_fields(None).is_empty());
+    }
+
+    #[test]
+    fn test_coerce_value() {
+        assert_eq!(
+            coerce_value(&Value::String("42".to_string()), CoerceTarget::Number),
+            Some(Value::Number(42.into()))
+        );
+        assert_eq!(
+            coerce_value(&Value::String("yes".to_string()), CoerceTarget::Boolean),
+            Some(Value::Bool(true))
+        );
+        assert_eq!(
+            coerce_value(&Value::String("a, b".to_string()), CoerceTarget::Array),
+            Some(serde_json::json!(["a", "b"]))
+        );
+        assert_eq!(
+            coerce_value(&Value::Number(3.into()), CoerceTarget::String),
+            Some(Value::String("3".to_string()))
+        );
+        assert_eq!(
+            coerce_value(
+                &Value::String("2024-01-05T10:00:00Z".to_string()),
+                CoerceTarget::Date
+            ),
+            Some(Value::String("2024-01-05".to_string()))
+        );
+        assert_eq!(
+            coerce_value(&Value::String("soon".to_string()), CoerceTarget::Number),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_schema_migrations() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_root = temp_dir.path();
+        let post = project_root.join("post.mdx");
+        let already_migrated = project_root.join("other.md");
+
+        fs::write(
+            &post,
+            "---\ntitle: Hello\npubDate: 2024-01-01\nrating: \"4\"\n---\n\nimport X from './X';\n\n# Body\n",
+        )
+        .unwrap();
+        fs::write(
+            &already_migrated,
+            "---\ntitle: Other\npublishedAt: 2024-02-01\nrating: 5\ncategory: news\n---\n\nBody\n",
+        )
+        .unwrap();
+
+        let result = apply_schema_migrations(
+            vec![
+                post.to_string_lossy().to_string(),
+                already_migrated.to_string_lossy().to_string(),
+            ],
+            vec![
+                FrontmatterMigration::RenameKey {
+                    from: "pubDate".to_string(),
+                    to: "publishedAt".to_string(),
+                },
+                FrontmatterMigration::CoerceType {
+                    key: "rating".to_string(),
+                    to: CoerceTarget::Number,
+                },
+                FrontmatterMigration::FillDefault {
+                    key: "category".to_string(),
+                    value: Value::String("news".to_string()),
+                },
+            ],
+            project_root.to_string_lossy().to_string(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.updated.len(), 1);
+        assert_eq!(result.unchanged.len(), 1);
+        assert!(result.failed.is_empty());
+
+        let migrated = fs::read_to_string(&post).unwrap();
+        assert_eq!(
+            migrated,
+            "---\ntitle: Hello\npublishedAt: 2024-01-01\nrating: 4\ncategory: news\n---\n\nimport X from './X';\n\n# Body\n"
+        );
+    }
+}
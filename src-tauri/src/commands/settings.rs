@@ -0,0 +1,710 @@
+use crate::commands::assets::{validate_template, AssetNaming, AssetPathStyle};
+use crate::commands::images::ImageProcessing;
+use crate::commands::watcher::WatcherMode;
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Component, Path};
+use tauri::{path::BaseDirectory, Manager};
+
+/// Settings file committed to the project repository, so a team can share editor configuration
+pub const PROJECT_SETTINGS_FILE: &str = ".astro-editor.json";
+
+/// Current settings format. Version 1 is what the project registry wrote before settings
+/// files were versioned.
+pub const SETTINGS_VERSION: u64 = 2;
+
+pub(crate) const MIN_POLL_INTERVAL_MS: u64 = 100;
+pub(crate) const MAX_POLL_INTERVAL_MS: u64 = 60_000;
+
+/// Directories version 1 saved into every project, whether or not the user chose them
+const LEGACY_DEFAULT_DIRECTORIES: [(&str, &str); 3] = [
+    ("contentDirectory", "src/content"),
+    ("assetsDirectory", "src/assets"),
+    ("mdxComponentsDirectory", "src/components/mdx"),
+];
+
+/// Frontmatter mappings version 1 saved into every project along with the directories
+const LEGACY_DEFAULT_MAPPINGS: [(&str, &str); 4] = [
+    ("publishedDate", "date"),
+    ("title", "title"),
+    ("description", "description"),
+    ("draft", "draft"),
+];
+
+/// Per-project settings, matching `ProjectSettings` in the frontend project registry.
+/// Every field is optional so layers can be merged; unset fields use the app defaults.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSettings {
+    #[serde(default)]
+    pub path_overrides: PathOverrides,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_patterns: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watcher: Option<WatcherSettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assets: Option<AssetSettings>,
+    #[serde(default)]
+    pub frontmatter_mappings: FrontmatterMappings,
+    #[serde(default)]
+    pub collection_view_settings: HashMap<String, CollectionViewSettings>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_directory: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assets_directory: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mdx_components_directory: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatcherSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<WatcherMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poll_interval_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path_style: Option<AssetPathStyle>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_template: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filename_template: Option<String>,
+    /// Naming templates for single collections, taking precedence over the ones above
+    #[serde(default)]
+    pub collections: HashMap<String, AssetNaming>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub images: Option<ImageProcessing>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontmatterMappings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_date: Option<FieldNames>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub draft: Option<String>,
+}
+
+/// A frontmatter field name, or several to try in order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FieldNames {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionViewSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_drafts_only: Option<bool>,
+}
+
+impl ProjectSettings {
+    /// Layers `overrides` on top of these settings, field by field
+    pub fn merge(mut self, overrides: ProjectSettings) -> Self {
+        let paths = overrides.path_overrides;
+        self.path_overrides.content_directory = paths
+            .content_directory
+            .or(self.path_overrides.content_directory);
+        self.path_overrides.assets_directory = paths
+            .assets_directory
+            .or(self.path_overrides.assets_directory);
+        self.path_overrides.mdx_components_directory = paths
+            .mdx_components_directory
+            .or(self.path_overrides.mdx_components_directory);
+
+        self.ignore_patterns = overrides.ignore_patterns.or(self.ignore_patterns);
+
+        self.watcher = match (self.watcher, overrides.watcher) {
+            (Some(base), Some(watcher)) => Some(WatcherSettings {
+                mode: watcher.mode.or(base.mode),
+                poll_interval_ms: watcher.poll_interval_ms.or(base.poll_interval_ms),
+            }),
+            (base, watcher) => watcher.or(base),
+        };
+
+        self.assets = match (self.assets, overrides.assets) {
+            (Some(mut base), Some(assets)) => {
+                for (collection, naming) in assets.collections {
+                    let entry = base.collections.entry(collection).or_default();
+                    entry.folder_template = naming.folder_template.or(entry.folder_template.take());
+                    entry.filename_template =
+                        naming.filename_template.or(entry.filename_template.take());
+                }
+                let images = match (base.images, assets.images) {
+                    (Some(base), Some(images)) => Some(ImageProcessing {
+                        max_width: images.max_width.or(base.max_width),
+                        max_height: images.max_height.or(base.max_height),
+                        strip_metadata: images.strip_metadata.or(base.strip_metadata),
+                        convert_to: images.convert_to.or(base.convert_to),
+                        quality: images.quality.or(base.quality),
+                    }),
+                    (base, images) => images.or(base),
+                };
+                Some(AssetSettings {
+                    path_style: assets.path_style.or(base.path_style),
+                    folder_template: assets.folder_template.or(base.folder_template),
+                    filename_template: assets.filename_template.or(base.filename_template),
+                    collections: base.collections,
+                    images,
+                })
+            }
+            (base, assets) => assets.or(base),
+        };
+
+        let mappings = overrides.frontmatter_mappings;
+        let base = self.frontmatter_mappings;
+        self.frontmatter_mappings = FrontmatterMappings {
+            published_date: mappings.published_date.or(base.published_date),
+            title: mappings.title.or(base.title),
+            description: mappings.description.or(base.description),
+            draft: mappings.draft.or(base.draft),
+        };
+
+        for (collection, view) in overrides.collection_view_settings {
+            let entry = self.collection_view_settings.entry(collection).or_default();
+            entry.show_drafts_only = view.show_drafts_only.or(entry.show_drafts_only);
+        }
+
+        self
+    }
+}
+
+/// Effective settings for a project: global defaults, then the project's
+/// `.astro-editor.json`, then the user's own settings for the project
+#[tauri::command]
+pub async fn get_effective_project_settings(
+    app: tauri::AppHandle,
+    project_path: String,
+    project_id: Option<String>,
+) -> Result<ProjectSettings, String> {
+    let preferences_dir = app
+        .path()
+        .resolve("preferences", BaseDirectory::AppLocalData)
+        .map_err(|e| format!("Failed to resolve app data directory: {e}"))?;
+
+    load_effective_settings(
+        &preferences_dir,
+        Path::new(&project_path),
+        project_id.as_deref(),
+    )
+}
+
+pub fn load_effective_settings(
+    preferences_dir: &Path,
+    project_path: &Path,
+    project_id: Option<&str>,
+) -> Result<ProjectSettings, String> {
+    let global_defaults = read_global_defaults(preferences_dir);
+    let team_settings = read_team_settings(project_path)?;
+    let user_settings = match project_id {
+        Some(project_id) => read_user_project_settings(preferences_dir, project_id)?,
+        None => None,
+    };
+
+    if team_settings.is_some() {
+        info!(
+            "Astro Editor [SETTINGS] Using {PROJECT_SETTINGS_FILE} from {}",
+            project_path.display()
+        );
+    }
+
+    Ok([global_defaults, team_settings, user_settings]
+        .into_iter()
+        .flatten()
+        .fold(ProjectSettings::default(), ProjectSettings::merge))
+}
+
+/// Reads the committed `.astro-editor.json`. Problems, including fields it doesn't know,
+/// are errors, so the team notices a broken file instead of silently getting the defaults.
+pub fn read_team_settings(project_path: &Path) -> Result<Option<ProjectSettings>, String> {
+    let path = project_path.join(PROJECT_SETTINGS_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {PROJECT_SETTINGS_FILE}: {e}"))?;
+    let mut value: Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse {PROJECT_SETTINGS_FILE}: {e}"))?;
+
+    let version = settings_version(&value, PROJECT_SETTINGS_FILE)?;
+    if let Some(object) = value.as_object_mut() {
+        object.remove("version");
+        object.remove("$schema");
+    }
+
+    let value =
+        migrate_settings(value, version).map_err(|e| format!("{PROJECT_SETTINGS_FILE}: {e}"))?;
+    let settings = deserialize_settings(&value, PROJECT_SETTINGS_FILE)?;
+
+    let known = serde_json::to_value(&settings)
+        .map_err(|e| format!("Failed to serialize settings: {e}"))?;
+    let mut unknown = Vec::new();
+    collect_unknown_fields(&value, &known, "", &mut unknown);
+    if !unknown.is_empty() {
+        return Err(format!(
+            "Unknown fields in {PROJECT_SETTINGS_FILE}: {}",
+            unknown.join(", ")
+        ));
+    }
+
+    Ok(Some(settings))
+}
+
+/// `defaultProjectSettings` from the user's global settings. The frontend already falls
+/// back to defaults for an unreadable file, so problems are only logged.
+fn read_global_defaults(preferences_dir: &Path) -> Option<ProjectSettings> {
+    let path = preferences_dir.join("global-settings.json");
+    let content = std::fs::read_to_string(path).ok()?;
+
+    let result = serde_json::from_str::<Value>(&content)
+        .map_err(|e| format!("Failed to parse global settings: {e}"))
+        .and_then(|mut value| {
+            let version = settings_version(&value, "global settings")?;
+            let defaults = value
+                .get_mut("defaultProjectSettings")
+                .map(Value::take)
+                .unwrap_or_else(|| Value::Object(Default::default()));
+            parse_settings(defaults, version, "global settings")
+        });
+
+    match result {
+        Ok(settings) => Some(settings),
+        Err(e) => {
+            warn!("Astro Editor [SETTINGS] Ignoring global project defaults: {e}");
+            None
+        }
+    }
+}
+
+/// The user's settings for one project, from the project registry
+fn read_user_project_settings(
+    preferences_dir: &Path,
+    project_id: &str,
+) -> Result<Option<ProjectSettings>, String> {
+    if project_id.is_empty() || project_id.contains(['/', '\\']) || project_id.contains("..") {
+        return Err(format!("Invalid project ID: {project_id}"));
+    }
+
+    let path = preferences_dir
+        .join("projects")
+        .join(format!("{project_id}.json"));
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Ok(None);
+    };
+
+    let result = serde_json::from_str::<Value>(&content)
+        .map_err(|e| format!("Failed to parse project settings: {e}"))
+        .and_then(|mut value| {
+            let version = settings_version(&value, "project settings")?;
+            match value.get_mut("settings").map(Value::take) {
+                Some(settings) => parse_settings(settings, version, "project settings"),
+                None => Ok(ProjectSettings::default()),
+            }
+        });
+
+    match result {
+        Ok(settings) => Ok(Some(settings)),
+        Err(e) => {
+            warn!("Astro Editor [SETTINGS] Ignoring settings for project {project_id}: {e}");
+            Ok(None)
+        }
+    }
+}
+
+/// The `version` of a settings file; files written before versioning are version 1
+fn settings_version(value: &Value, source: &str) -> Result<u64, String> {
+    match value.get("version") {
+        None | Some(Value::Null) => Ok(1),
+        Some(version) => version
+            .as_u64()
+            .ok_or_else(|| format!("Invalid version in {source}: {version}")),
+    }
+}
+
+/// Migrates a settings object to the current version, then deserializes and validates it.
+/// Fields it doesn't know are ignored, so settings saved by a newer app still load.
+fn parse_settings(value: Value, version: u64, source: &str) -> Result<ProjectSettings, String> {
+    let value = migrate_settings(value, version).map_err(|e| format!("{source}: {e}"))?;
+    deserialize_settings(&value, source)
+}
+
+fn deserialize_settings(value: &Value, source: &str) -> Result<ProjectSettings, String> {
+    let settings = ProjectSettings::deserialize(value)
+        .map_err(|e| format!("Invalid settings in {source}: {e}"))?;
+    validate_settings(&settings).map_err(|e| format!("Invalid settings in {source}: {e}"))?;
+    Ok(settings)
+}
+
+/// Finds fields in `value` that didn't survive deserializing, by comparing it with the
+/// settings serialized again (`known`). Paths are dotted, like `watcher.pollInterval`.
+fn collect_unknown_fields(value: &Value, known: &Value, prefix: &str, unknown: &mut Vec<String>) {
+    let Some(object) = value.as_object() else {
+        return;
+    };
+    for (key, field) in object {
+        let path = format!("{prefix}{key}");
+        match known.get(key) {
+            Some(known_field) => {
+                collect_unknown_fields(field, known_field, &format!("{path}."), unknown)
+            }
+            // Unset optional fields aren't serialized
+            None if field.is_null() => {}
+            None => unknown.push(path),
+        }
+    }
+}
+
+fn migrate_settings(mut settings: Value, version: u64) -> Result<Value, String> {
+    if version > SETTINGS_VERSION {
+        return Err(format!(
+            "settings version {version} is newer than this version of Astro Editor supports ({SETTINGS_VERSION})"
+        ));
+    }
+
+    if version < 2 {
+        debug!("Astro Editor [SETTINGS] Migrating settings from version {version} to 2");
+        migrate_v1_to_v2(&mut settings);
+    }
+
+    Ok(settings)
+}
+
+/// Version 1 saved the default directories and frontmatter mappings into every project,
+/// which would now take precedence over astro.config and `.astro-editor.json`. Those are
+/// dropped and the remaining directories are normalized.
+fn migrate_v1_to_v2(settings: &mut Value) {
+    if let Some(mappings) = settings
+        .get_mut("frontmatterMappings")
+        .and_then(Value::as_object_mut)
+    {
+        for (name, default) in LEGACY_DEFAULT_MAPPINGS {
+            if mappings.get(name).and_then(Value::as_str) == Some(default) {
+                mappings.remove(name);
+            }
+        }
+    }
+
+    let Some(overrides) = settings
+        .get_mut("pathOverrides")
+        .and_then(Value::as_object_mut)
+    else {
+        return;
+    };
+
+    let keys: Vec<String> = overrides.keys().cloned().collect();
+    for key in keys {
+        let Some(dir) = overrides.get(&key).and_then(Value::as_str) else {
+            continue;
+        };
+        let dir = normalize_directory(dir);
+
+        let is_legacy_default = LEGACY_DEFAULT_DIRECTORIES
+            .iter()
+            .any(|(name, default)| *name == key && *default == dir);
+        if is_legacy_default || dir.is_empty() {
+            overrides.remove(&key);
+        } else {
+            overrides.insert(key, Value::String(dir));
+        }
+    }
+}
+
+fn normalize_directory(dir: &str) -> String {
+    let mut dir = dir.trim();
+    while let Some(rest) = dir.strip_prefix("./") {
+        dir = rest;
+    }
+    dir.trim_end_matches('/').to_string()
+}
+
+/// Checks values that deserialize fine but can't be used
+pub fn validate_settings(settings: &ProjectSettings) -> Result<(), String> {
+    let mut problems = Vec::new();
+
+    let paths = &settings.path_overrides;
+    for (name, dir) in [
+        ("contentDirectory", &paths.content_directory),
+        ("assetsDirectory", &paths.assets_directory),
+        ("mdxComponentsDirectory", &paths.mdx_components_directory),
+    ] {
+        let Some(dir) = dir else { continue };
+        let path = Path::new(dir);
+        if dir.trim().is_empty()
+            || path.is_absolute()
+            || path.components().any(|c| c == Component::ParentDir)
+        {
+            problems.push(format!(
+                "pathOverrides.{name} must be a directory inside the project, got '{dir}'"
+            ));
+        }
+    }
+
+    if let Some(patterns) = &settings.ignore_patterns {
+        if patterns.iter().any(|pattern| pattern.trim().is_empty()) {
+            problems.push("ignorePatterns must not contain empty patterns".to_string());
+        }
+    }
+
+    if let Some(assets) = &settings.assets {
+        let project_naming = AssetNaming {
+            folder_template: assets.folder_template.clone(),
+            filename_template: assets.filename_template.clone(),
+        };
+        let collection_naming = assets
+            .collections
+            .iter()
+            .map(|(collection, naming)| (format!("assets.collections.{collection}"), naming));
+
+        for (name, naming) in
+            std::iter::once(("assets".to_string(), &project_naming)).chain(collection_naming)
+        {
+            for (field, template, is_filename) in [
+                ("folderTemplate", &naming.folder_template, false),
+                ("filenameTemplate", &naming.filename_template, true),
+            ] {
+                if let Some(Err(e)) = template
+                    .as_deref()
+                    .map(|t| validate_template(t, is_filename))
+                {
+                    problems.push(format!("{name}.{field}: {e}"));
+                }
+            }
+        }
+    }
+
+    if let Some(images) = settings.assets.as_ref().and_then(|a| a.images.as_ref()) {
+        for (name, value) in [
+            ("maxWidth", images.max_width),
+            ("maxHeight", images.max_height),
+        ] {
+            if value == Some(0) {
+                problems.push(format!("assets.images.{name} must be at least 1"));
+            }
+        }
+        if let Some(quality) = images
+            .quality
+            .filter(|quality| !(1..=100).contains(quality))
+        {
+            problems.push(format!(
+                "assets.images.quality must be between 1 and 100, got {quality}"
+            ));
+        }
+    }
+
+    if let Some(interval) = settings
+        .watcher
+        .as_ref()
+        .and_then(|watcher| watcher.poll_interval_ms)
+    {
+        if !(MIN_POLL_INTERVAL_MS..=MAX_POLL_INTERVAL_MS).contains(&interval) {
+            problems.push(format!(
+                "watcher.pollIntervalMs must be between {MIN_POLL_INTERVAL_MS} and {MAX_POLL_INTERVAL_MS}, got {interval}"
+            ));
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems.join("; "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_v1_settings_are_migrated() {
+        let settings = parse_settings(
+            serde_json::json!({
+                "pathOverrides": {
+                    "contentDirectory": "src/content/",
+                    "assetsDirectory": "./public/images/",
+                    "mdxComponentsDirectory": "src/components/mdx/"
+                },
+                "frontmatterMappings": { "publishedDate": "date", "title": "title" },
+                "collectionViewSettings": {}
+            }),
+            1,
+            "test",
+        )
+        .unwrap();
+
+        assert_eq!(
+            settings.path_overrides,
+            PathOverrides {
+                assets_directory: Some("public/images".to_string()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            settings.frontmatter_mappings,
+            FrontmatterMappings::default()
+        );
+
+        // Current-version files are taken as written
+        let settings = parse_settings(
+            serde_json::json!({
+                "pathOverrides": { "contentDirectory": "src/content" },
+                "frontmatterMappings": { "publishedDate": ["pubDate", "date"] }
+            }),
+            SETTINGS_VERSION,
+            "test",
+        )
+        .unwrap();
+        assert_eq!(
+            settings.path_overrides.content_directory.as_deref(),
+            Some("src/content")
+        );
+        assert_eq!(
+            settings.frontmatter_mappings.published_date,
+            Some(FieldNames::Many(vec![
+                "pubDate".to_string(),
+                "date".to_string()
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_invalid_settings_are_rejected() {
+        let newer = parse_settings(serde_json::json!({}), SETTINGS_VERSION + 1, "test");
+        assert!(newer.unwrap_err().contains("newer"));
+
+        let invalid = parse_settings(
+            serde_json::json!({
+                "pathOverrides": { "contentDirectory": "../elsewhere" },
+                "watcher": { "mode": "poll", "pollIntervalMs": 5 },
+                "assets": {
+                    "collections": { "blog": { "filenameTemplate": "{title}.{ext}" } },
+                    "images": { "maxWidth": 0, "quality": 120 }
+                }
+            }),
+            SETTINGS_VERSION,
+            "test",
+        )
+        .unwrap_err();
+        assert!(invalid.contains("pathOverrides.contentDirectory"));
+        assert!(invalid.contains("watcher.pollIntervalMs"));
+        assert!(invalid.contains("assets.collections.blog.filenameTemplate"));
+        assert!(invalid.contains("assets.images.maxWidth"));
+        assert!(invalid.contains("assets.images.quality"));
+    }
+
+    #[test]
+    fn test_settings_layers_are_merged() {
+        let temp_dir = TempDir::new().unwrap();
+        let preferences = temp_dir.path().join("preferences");
+        let project = temp_dir.path().join("site");
+        fs::create_dir_all(preferences.join("projects")).unwrap();
+        fs::create_dir_all(&project).unwrap();
+
+        fs::write(
+            preferences.join("global-settings.json"),
+            r#"{ "version": 1, "general": {}, "defaultProjectSettings": {
+                "pathOverrides": { "contentDirectory": "src/content/" },
+                "frontmatterMappings": { "title": "name", "draft": "draft" }
+            } }"#,
+        )
+        .unwrap();
+        fs::write(
+            project.join(PROJECT_SETTINGS_FILE),
+            r#"{ "version": 2, "pathOverrides": { "contentDirectory": "docs/content" },
+                 "frontmatterMappings": { "title": "heading" },
+                 "ignorePatterns": ["drafts/"], "assets": { "pathStyle": "relative" } }"#,
+        )
+        .unwrap();
+        fs::write(
+            preferences.join("projects/site.json"),
+            r#"{ "metadata": { "id": "site" }, "settings": {
+                "pathOverrides": { "contentDirectory": "src/content/" },
+                "frontmatterMappings": { "title": "title", "draft": "hidden" },
+                "collectionViewSettings": { "blog": { "showDraftsOnly": true } }
+            } }"#,
+        )
+        .unwrap();
+
+        let settings = load_effective_settings(&preferences, &project, Some("site")).unwrap();
+
+        // The user's stored defaults were dropped by the migration, so the team's values win
+        assert_eq!(
+            settings.path_overrides.content_directory.as_deref(),
+            Some("docs/content")
+        );
+        assert_eq!(
+            settings.frontmatter_mappings.title.as_deref(),
+            Some("heading")
+        );
+        assert_eq!(
+            settings.frontmatter_mappings.draft.as_deref(),
+            Some("hidden")
+        );
+        assert_eq!(settings.ignore_patterns, Some(vec!["drafts/".to_string()]));
+        assert_eq!(
+            settings.assets.and_then(|assets| assets.path_style),
+            Some(AssetPathStyle::Relative)
+        );
+        assert_eq!(
+            settings.collection_view_settings["blog"].show_drafts_only,
+            Some(true)
+        );
+
+        assert!(load_effective_settings(&preferences, &project, Some("../site")).is_err());
+    }
+
+    #[test]
+    fn test_unknown_fields_only_fail_the_team_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let preferences = temp_dir.path().join("preferences");
+        let project = temp_dir.path().join("site");
+        fs::create_dir_all(&preferences).unwrap();
+        fs::create_dir_all(&project).unwrap();
+
+        // Stored settings keep working when they have fields this version doesn't know
+        fs::write(
+            preferences.join("global-settings.json"),
+            r#"{ "version": 2, "defaultProjectSettings": {
+                "pathOverrides": { "contentDirectory": "content" },
+                "spellcheck": { "language": "en" }
+            } }"#,
+        )
+        .unwrap();
+        let settings = load_effective_settings(&preferences, &project, None).unwrap();
+        assert_eq!(
+            settings.path_overrides.content_directory.as_deref(),
+            Some("content")
+        );
+
+        fs::write(
+            project.join(PROJECT_SETTINGS_FILE),
+            r#"{ "version": 2, "pathOverides": {}, "watcher": { "mode": "poll", "interval": 500 },
+                 "assets": { "collections": {}, "images": null } }"#,
+        )
+        .unwrap();
+        let error = read_team_settings(&project).unwrap_err();
+        assert!(error.contains("pathOverides"));
+        assert!(error.contains("watcher.interval"));
+        assert!(!error.contains("assets"));
+    }
+}
@@ -0,0 +1,328 @@
+use crate::commands::files::parse_frontmatter_internal;
+use crate::commands::project::scan_collection_files;
+use crate::models::ContentStats;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Average adult silent reading speed used for reading time estimates
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Patterns used to count and strip Markdown, compiled once
+struct StatsPatterns {
+    heading: Regex,
+    image: Regex,
+    link: Regex,
+    markdown_image: Regex,
+    prose_replacements: Vec<(Regex, &'static str)>,
+}
+
+static STATS_PATTERNS: OnceLock<StatsPatterns> = OnceLock::new();
+
+fn stats_patterns() -> &'static StatsPatterns {
+    STATS_PATTERNS.get_or_init(|| StatsPatterns {
+        heading: Regex::new(r"(?m)^\s{0,3}#{1,6}\s+\S").unwrap(),
+        image: Regex::new(r#"!\[[^\]]*\]\([^)]*\)|<(?:img|Image|Picture)\b"#).unwrap(),
+        link: Regex::new(r#"\[[^\]]*\]\([^)]*\)|<a\s[^>]*href=|<https?://[^>]+>"#).unwrap(),
+        markdown_image: Regex::new(r"!\[[^\]]*\]\([^)]*\)").unwrap(),
+        prose_replacements: [
+            (r"`[^`]*`", ""),                               // Inline code
+            (r"!\[([^\]]*)\]\([^)]*\)", ""),                // Images
+            (r"\[([^\]]*)\]\([^)]*\)", "$1"),               // Links keep their text
+            (r"<[^>]+>", " "),                              // HTML and JSX tags
+            (r"(?m)^\s{0,3}(#{1,6}|>|[-*+]|\d+\.)\s+", ""), // Block markers
+            (r"[*_~]{1,3}", ""),                            // Emphasis markers
+        ]
+        .into_iter()
+        .map(|(pattern, replacement)| (Regex::new(pattern).unwrap(), replacement))
+        .collect(),
+    })
+}
+
+/// Frontmatter fields checked (in order) for the publish date when none is configured
+const DEFAULT_DATE_FIELDS: &[&str] = &["pubDate", "publishDate", "publishedAt", "date"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileStats {
+    pub id: String,
+    pub path: String,
+    pub is_draft: bool,
+    pub published_month: Option<String>, // "YYYY-MM"
+    pub stats: ContentStats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonthlyCount {
+    pub month: String, // "YYYY-MM"
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionStats {
+    pub collection: String,
+    pub file_count: usize,
+    pub draft_count: usize,
+    pub published_count: usize,
+    pub total_words: usize,
+    pub average_words: usize,
+    pub total_reading_time_minutes: u32,
+    pub files: Vec<FileStats>,
+    pub publishing_cadence: Vec<MonthlyCount>, // Published files per month, oldest first
+}
+
+#[tauri::command]
+pub async fn get_file_stats(
+    file_path: String,
+    project_root: String,
+) -> Result<ContentStats, String> {
+    let content = crate::commands::files::read_file(file_path, project_root).await?;
+    compute_content_stats(&content)
+}
+
+#[tauri::command]
+pub async fn get_collection_stats(
+    collection_path: String,
+    date_field: Option<String>,
+) -> Result<CollectionStats, String> {
+    let collection = Path::new(&collection_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    let entries = scan_collection_files(collection_path, None).await?;
+    let mut files = Vec::with_capacity(entries.len());
+
+    for entry in entries {
+        let Ok(content) = std::fs::read_to_string(&entry.path) else {
+            continue;
+        };
+        let Ok(stats) = compute_content_stats(&content) else {
+            continue;
+        };
+
+        let published_month = entry
+            .frontmatter
+            .as_ref()
+            .and_then(|frontmatter| match &date_field {
+                Some(field) => frontmatter.get(field),
+                None => DEFAULT_DATE_FIELDS
+                    .iter()
+                    .find_map(|field| frontmatter.get(*field)),
+            })
+            .and_then(|value| value.as_str())
+            .and_then(month_of_date);
+
+        files.push(FileStats {
+            id: entry.id,
+            path: entry.path.to_string_lossy().to_string(),
+            is_draft: entry.is_draft,
+            published_month,
+            stats,
+        });
+    }
+
+    Ok(summarize_collection(collection, files))
+}
+
+fn summarize_collection(collection: String, files: Vec<FileStats>) -> CollectionStats {
+    let draft_count = files.iter().filter(|f| f.is_draft).count();
+    let total_words: usize = files.iter().map(|f| f.stats.word_count).sum();
+
+    let mut months: BTreeMap<String, usize> = BTreeMap::new();
+    for file in files.iter().filter(|f| !f.is_draft) {
+        if let Some(month) = &file.published_month {
+            *months.entry(month.clone()).or_default() += 1;
+        }
+    }
+
+    CollectionStats {
+        collection,
+        file_count: files.len(),
+        draft_count,
+        published_count: files.len() - draft_count,
+        total_words,
+        average_words: total_words.checked_div(files.len()).unwrap_or(0),
+        total_reading_time_minutes: files.iter().map(|f| f.stats.reading_time_minutes).sum(),
+        publishing_cadence: months
+            .into_iter()
+            .map(|(month, count)| MonthlyCount { month, count })
+            .collect(),
+        files,
+    }
+}
+
+/// Extracts "YYYY-MM" from a date or ISO datetime string
+fn month_of_date(value: &str) -> Option<String> {
+    let date_part = value.trim().split('T').next()?;
+    chrono::NaiveDate::parse_from_str(date_part, "%Y-%m-%d")
+        .ok()
+        .map(|date| date.format("%Y-%m").to_string())
+}
+
+pub fn compute_content_stats(file_content: &str) -> Result<ContentStats, String> {
+    // Drops frontmatter and MDX imports
+    let parsed = parse_frontmatter_internal(file_content)?;
+    let body = strip_code_blocks(&parsed.content);
+
+    let patterns = stats_patterns();
+    let image_count = patterns.image.find_iter(&body).count();
+    // Markdown images also match the link pattern, so don't count them twice
+    let link_count = patterns
+        .link
+        .find_iter(&body)
+        .count()
+        .saturating_sub(patterns.markdown_image.find_iter(&body).count());
+
+    let prose = markdown_to_prose(&body);
+    let word_count = prose
+        .split_whitespace()
+        .filter(|word| word.chars().any(|c| c.is_alphanumeric()))
+        .count();
+    let character_count = prose.chars().filter(|c| !c.is_whitespace()).count();
+
+    Ok(ContentStats {
+        word_count,
+        character_count,
+        reading_time_minutes: word_count.div_ceil(WORDS_PER_MINUTE) as u32,
+        heading_count: patterns.heading.find_iter(&body).count(),
+        image_count,
+        link_count,
+    })
+}
+
+/// Removes fenced code blocks (``` and ~~~) from Markdown content
+fn strip_code_blocks(content: &str) -> String {
+    let mut result = Vec::new();
+    let mut fence: Option<&str> = None;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        match fence {
+            Some(marker) => {
+                if trimmed.starts_with(marker) {
+                    fence = None;
+                }
+            }
+            None => {
+                if trimmed.starts_with("```") {
+                    fence = Some("```");
+                } else if trimmed.starts_with("~~~") {
+                    fence = Some("~~~");
+                } else {
+                    result.push(line);
+                }
+            }
+        }
+    }
+
+    result.join("\n")
+}
+
+/// Reduces Markdown/MDX to the text a reader would actually see
+fn markdown_to_prose(content: &str) -> String {
+    stats_patterns()
+        .prose_replacements
+        .iter()
+        .fold(content.to_string(), |text, (pattern, replacement)| {
+            pattern.replace_all(&text, *replacement).to_string()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_compute_content_stats() {
+        let content = r#"---
+title: A post with lots of words in the frontmatter
+---
+
+import Callout from '../components/Callout.astro';
+
+# Hello world
+
+This is **bold** and a [link](https://example.com).
+
+![Alt text](./image.png)
+
+```js
+const ignored = "these words do not count";
+```
+
+<Callout type="info">Component text counts</Callout>
+"#;
+
+        let stats = compute_content_stats(content).unwrap();
+
+        // Hello world / This is bold and a link / Component text counts
+        assert_eq!(stats.word_count, 11);
+        assert_eq!(stats.heading_count, 1);
+        assert_eq!(stats.image_count, 1);
+        assert_eq!(stats.link_count, 1);
+        assert_eq!(stats.reading_time_minutes, 1);
+        assert_eq!(
+            stats.character_count,
+            "HelloworldThisisboldandalink.Componenttextcounts".len()
+        );
+    }
+
+    #[test]
+    fn test_empty_content_stats() {
+        let stats = compute_content_stats("---\ntitle: Empty\n---\n").unwrap();
+        assert_eq!(stats, ContentStats::default());
+    }
+
+    #[test]
+    fn test_month_of_date() {
+        assert_eq!(month_of_date("2024-03-15"), Some("2024-03".to_string()));
+        assert_eq!(
+            month_of_date("2024-03-15T10:00:00Z"),
+            Some("2024-03".to_string())
+        );
+        assert_eq!(month_of_date("next week"), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_collection_stats() {
+        let temp_dir = TempDir::new().unwrap();
+        let collection = temp_dir.path().join("blog");
+        fs::create_dir_all(&collection).unwrap();
+
+        fs::write(
+            collection.join("one.md"),
+            "---\ntitle: One\npubDate: 2024-01-10\n---\n\nOne two three four.\n",
+        )
+        .unwrap();
+        fs::write(
+            collection.join("two.md"),
+            "---\ntitle: Two\npubDate: 2024-01-20\n---\n\nFive six.\n",
+        )
+        .unwrap();
+        fs::write(
+            collection.join("three.md"),
+            "---\ntitle: Three\npubDate: 2024-02-01\ndraft: true\n---\n\nSeven.\n",
+        )
+        .unwrap();
+
+        let stats = get_collection_stats(collection.to_string_lossy().to_string(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.collection, "blog");
+        assert_eq!(stats.file_count, 3);
+        assert_eq!(stats.draft_count, 1);
+        assert_eq!(stats.published_count, 2);
+        assert_eq!(stats.total_words, 7);
+        assert_eq!(stats.average_words, 2);
+        // Drafts don't count towards publishing cadence
+        assert_eq!(stats.publishing_cadence.len(), 1);
+        assert_eq!(stats.publishing_cadence[0].month, "2024-01");
+        assert_eq!(stats.publishing_cadence[0].count, 2);
+    }
+}
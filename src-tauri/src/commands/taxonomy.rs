@@ -0,0 +1,291 @@
+use crate::commands::files::{update_frontmatter_bulk, BulkFrontmatterResult};
+use crate::commands::project::scan_collection_files;
+use crate::models::FileEntry;
+use log::info;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxonomyValue {
+    pub value: String,
+    pub count: usize,
+    pub files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxonomyField {
+    pub field: String,
+    pub values: Vec<TaxonomyValue>,
+}
+
+/// Aggregates frontmatter values across a collection, with usage counts and files
+///
+/// When `fields` isn't given, every array field is included, plus any string field
+/// where at least one value is shared between files (e.g. `category` or `author`).
+#[tauri::command]
+pub async fn get_taxonomy(
+    collection_path: String,
+    fields: Option<Vec<String>>,
+) -> Result<Vec<TaxonomyField>, String> {
+    let files = scan_collection_files(collection_path, None).await?;
+    Ok(aggregate_taxonomy(&files, fields.as_deref()))
+}
+
+/// Renames a taxonomy value in every file of a collection
+///
+/// Renaming to a value that already exists merges the two, so `[js, javascript]`
+/// becomes `[javascript]` rather than containing a duplicate.
+#[tauri::command]
+pub async fn rename_taxonomy_value(
+    collection_path: String,
+    field: String,
+    from: String,
+    to: String,
+    project_root: String,
+) -> Result<BulkFrontmatterResult, String> {
+    let to = to.trim().to_string();
+    if to.is_empty() {
+        return Err("New value cannot be empty".to_string());
+    }
+
+    let files = scan_collection_files(collection_path, None).await?;
+    let file_paths: Vec<String> = files
+        .iter()
+        .filter(|file| {
+            file.frontmatter
+                .as_ref()
+                .and_then(|frontmatter| frontmatter.get(&field))
+                .is_some_and(|value| field_values(value).contains(&from.as_str()))
+        })
+        .map(|file| file.path.to_string_lossy().to_string())
+        .collect();
+
+    info!(
+        "Astro Editor [TAXONOMY] Renaming {field} value '{from}' to '{to}' in {} files",
+        file_paths.len()
+    );
+
+    Ok(update_frontmatter_bulk(
+        &file_paths,
+        &project_root,
+        |frontmatter| match frontmatter.get_mut(&field) {
+            Some(value) => rename_in_value(value, &from, &to),
+            None => false,
+        },
+    ))
+}
+
+fn aggregate_taxonomy(files: &[FileEntry], fields: Option<&[String]>) -> Vec<TaxonomyField> {
+    // field -> value -> files using it
+    let mut usage: BTreeMap<String, HashMap<String, Vec<String>>> = BTreeMap::new();
+    let mut array_fields = std::collections::HashSet::new();
+
+    for file in files {
+        let Some(frontmatter) = &file.frontmatter else {
+            continue;
+        };
+        let file_path = file.path.to_string_lossy().to_string();
+
+        for (key, value) in frontmatter {
+            if fields.is_some_and(|wanted| !wanted.contains(key)) {
+                continue;
+            }
+            if fields.is_none() && matches!(value, Value::String(s) if looks_like_date(s)) {
+                continue;
+            }
+            if value.is_array() {
+                array_fields.insert(key.clone());
+            }
+
+            let values = usage.entry(key.clone()).or_default();
+            let mut seen = Vec::new();
+            for item in field_values(value) {
+                let item = item.trim();
+                if item.is_empty() || seen.contains(&item) {
+                    continue;
+                }
+                seen.push(item);
+                values
+                    .entry(item.to_string())
+                    .or_default()
+                    .push(file_path.clone());
+            }
+        }
+    }
+
+    usage
+        .into_iter()
+        .filter(|(field, values)| {
+            fields.is_some()
+                || array_fields.contains(field)
+                || values.values().any(|files| files.len() > 1)
+        })
+        .map(|(field, values)| {
+            let mut values: Vec<TaxonomyValue> = values
+                .into_iter()
+                .map(|(value, files)| TaxonomyValue {
+                    value,
+                    count: files.len(),
+                    files,
+                })
+                .collect();
+            values.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+            TaxonomyField { field, values }
+        })
+        .filter(|field| !field.values.is_empty())
+        .collect()
+}
+
+/// The string values held by a frontmatter field, whether it's a single string or an array
+fn field_values(value: &Value) -> Vec<&str> {
+    match value {
+        Value::String(s) => vec![s.as_str()],
+        Value::Array(items) => items.iter().filter_map(|item| item.as_str()).collect(),
+        _ => vec![],
+    }
+}
+
+fn looks_like_date(value: &str) -> bool {
+    let date_part = value.split('T').next().unwrap_or(value);
+    chrono::NaiveDate::parse_from_str(date_part, "%Y-%m-%d").is_ok()
+}
+
+fn rename_in_value(value: &mut Value, from: &str, to: &str) -> bool {
+    match value {
+        Value::String(s) if s == from => {
+            *s = to.to_string();
+            true
+        }
+        Value::Array(items) => {
+            if !items.iter().any(|item| item.as_str() == Some(from)) {
+                return false;
+            }
+            let mut renamed: Vec<Value> = Vec::with_capacity(items.len());
+            for item in items.drain(..) {
+                let item = if item.as_str() == Some(from) {
+                    Value::String(to.to_string())
+                } else {
+                    item
+                };
+                if !renamed.contains(&item) {
+                    renamed.push(item);
+                }
+            }
+            *items = renamed;
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_post(dir: &std::path::Path, name: &str, frontmatter: &str) {
+        fs::write(dir.join(name), format!("---\n{frontmatter}\n---\n\nBody\n")).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_get_taxonomy_aggregates_values() {
+        let temp_dir = TempDir::new().unwrap();
+        let collection = temp_dir.path().join("blog");
+        fs::create_dir_all(&collection).unwrap();
+
+        write_post(
+            &collection,
+            "one.md",
+            "title: One\nauthor: Sam\npubDate: 2024-01-01\ntags: [js, rust]",
+        );
+        write_post(
+            &collection,
+            "two.md",
+            "title: Two\nauthor: Sam\npubDate: 2024-01-02\ntags:\n  - js",
+        );
+        write_post(&collection, "three.md", "title: Three\nauthor: Alex");
+
+        let taxonomy = get_taxonomy(collection.to_string_lossy().to_string(), None)
+            .await
+            .unwrap();
+
+        let field_names: Vec<&str> = taxonomy.iter().map(|f| f.field.as_str()).collect();
+        // Titles are all unique and dates are skipped, so neither is treated as a taxonomy
+        assert_eq!(field_names, vec!["author", "tags"]);
+
+        let tags = taxonomy.iter().find(|f| f.field == "tags").unwrap();
+        assert_eq!(tags.values[0].value, "js");
+        assert_eq!(tags.values[0].count, 2);
+        assert_eq!(tags.values[1].value, "rust");
+        assert_eq!(tags.values[1].files.len(), 1);
+
+        let authors = taxonomy.iter().find(|f| f.field == "author").unwrap();
+        assert_eq!(authors.values[0].value, "Sam");
+        assert_eq!(authors.values[0].count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_taxonomy_with_explicit_fields() {
+        let temp_dir = TempDir::new().unwrap();
+        let collection = temp_dir.path().join("blog");
+        fs::create_dir_all(&collection).unwrap();
+
+        write_post(&collection, "one.md", "title: One\ntags: [a]");
+
+        let taxonomy = get_taxonomy(
+            collection.to_string_lossy().to_string(),
+            Some(vec!["title".to_string()]),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(taxonomy.len(), 1);
+        assert_eq!(taxonomy[0].field, "title");
+        assert_eq!(taxonomy[0].values[0].value, "One");
+    }
+
+    #[test]
+    fn test_rename_in_value_merges_duplicates() {
+        let mut tags = serde_json::json!(["js", "javascript", "rust"]);
+        assert!(rename_in_value(&mut tags, "js", "javascript"));
+        assert_eq!(tags, serde_json::json!(["javascript", "rust"]));
+
+        let mut category = Value::String("js".to_string());
+        assert!(rename_in_value(&mut category, "js", "javascript"));
+        assert_eq!(category, "javascript");
+
+        assert!(!rename_in_value(&mut category, "python", "py"));
+    }
+
+    #[tokio::test]
+    async fn test_rename_taxonomy_value() {
+        let temp_dir = TempDir::new().unwrap();
+        let collection = temp_dir.path().join("blog");
+        fs::create_dir_all(&collection).unwrap();
+
+        write_post(&collection, "one.md", "title: One\ntags:\n  - js\n  - rust");
+        write_post(&collection, "two.md", "title: Two\ntags: [python]");
+
+        let result = rename_taxonomy_value(
+            collection.to_string_lossy().to_string(),
+            "tags".to_string(),
+            "js".to_string(),
+            "javascript".to_string(),
+            temp_dir.path().to_string_lossy().to_string(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.updated.len(), 1);
+        assert!(result.failed.is_empty());
+
+        let updated = fs::read_to_string(collection.join("one.md")).unwrap();
+        assert!(updated.contains("  - javascript\n  - rust\n"));
+        assert!(!updated.contains("  - js\n"));
+
+        let untouched = fs::read_to_string(collection.join("two.md")).unwrap();
+        assert!(untouched.contains("tags: [python]"));
+    }
+}
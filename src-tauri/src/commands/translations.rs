@@ -0,0 +1,442 @@
+use crate::astro_config::load_astro_project_config;
+use crate::commands::project::scan_collection_files;
+use crate::models::FileEntry;
+use crate::paths::to_slash_path;
+use crate::project_ignore::find_project_root;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Translation state of every entry in a collection
+#[derive(Debug, Clone, Serialize)]
+pub struct TranslationStatus {
+    pub locales: Vec<String>,
+    pub source_locale: Option<String>, // The locale other translations are compared against
+    pub entries: Vec<TranslationGroup>,
+}
+
+/// All translations of one entry
+#[derive(Debug, Clone, Serialize)]
+pub struct TranslationGroup {
+    pub key: String,
+    pub translations: Vec<Translation>,
+    pub missing: Vec<String>,
+    pub outdated: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Translation {
+    pub locale: String,
+    pub path: PathBuf,
+    pub last_modified: Option<u64>,
+    pub outdated: bool,
+}
+
+/// How a collection is split into locales
+#[derive(Debug, Clone, Default)]
+pub struct LocaleLayout {
+    configured: Vec<String>, // From astro.config's i18n section; empty means detect from files
+    default_locale: Option<String>,
+    locale_dirs: Vec<String>, // Subfolders of the collection named after a locale
+    locale_suffixes: Vec<String>, // `.<locale>` filename suffixes, when not configured
+}
+
+impl LocaleLayout {
+    /// Reads the locales from astro.config, or detects them from the folder layout
+    pub fn detect(collection_path: &Path, project_root: &Path) -> Self {
+        let i18n = load_astro_project_config(project_root)
+            .i18n
+            .unwrap_or_default();
+        let mut layout = Self {
+            configured: i18n.locales,
+            default_locale: i18n.default_locale,
+            ..Default::default()
+        };
+
+        let mut subdirs = Vec::new();
+        let mut stems = Vec::new();
+        for entry in std::fs::read_dir(collection_path)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+        {
+            let path = entry.path();
+            if path.is_dir() {
+                subdirs.extend(
+                    path.file_name()
+                        .and_then(|n| n.to_str())
+                        .map(str::to_string),
+                );
+            } else if matches!(
+                path.extension().and_then(|ext| ext.to_str()),
+                Some("md") | Some("mdx")
+            ) {
+                stems.extend(
+                    path.file_stem()
+                        .and_then(|n| n.to_str())
+                        .map(str::to_string),
+                );
+            }
+        }
+
+        layout.locale_dirs = subdirs
+            .into_iter()
+            .filter(|name| layout.is_locale(name))
+            .collect();
+        // Without a config, a single short folder name (`js`, `img`) isn't a locale layout
+        if layout.configured.is_empty() && layout.locale_dirs.len() < 2 {
+            layout.locale_dirs.clear();
+        }
+        layout.locale_dirs.sort();
+
+        if layout.configured.is_empty() {
+            layout.locale_suffixes = detect_locale_suffixes(&stems);
+        }
+
+        layout
+    }
+
+    pub fn has_locale_dirs(&self) -> bool {
+        !self.locale_dirs.is_empty()
+    }
+
+    pub fn is_locale_dir(&self, name: &str) -> bool {
+        self.locale_dirs.iter().any(|dir| dir == name)
+    }
+
+    fn is_locale(&self, name: &str) -> bool {
+        if self.configured.is_empty() {
+            is_locale_code(name)
+        } else {
+            self.configured.iter().any(|locale| locale == name)
+        }
+    }
+
+    fn is_locale_suffix(&self, suffix: &str) -> bool {
+        if self.configured.is_empty() {
+            self.locale_suffixes.iter().any(|locale| locale == suffix)
+        } else {
+            self.is_locale(suffix)
+        }
+    }
+
+    /// Works out a file's locale and translation key from its locale folder, a `.<locale>`
+    /// filename suffix or `lang`/`locale` frontmatter. A `translationKey` frontmatter field
+    /// links translations whose paths differ. Returns `(locale, key, relative_id)`, where the
+    /// relative ID is the extensionless path inside the collection.
+    pub fn file_locale(
+        &self,
+        relative_path: &Path,
+        frontmatter: Option<&HashMap<String, Value>>,
+    ) -> Option<(String, String, String)> {
+        let relative_id = to_slash_path(&relative_path.with_extension(""));
+        let string_field = |name: &str| frontmatter?.get(name)?.as_str().map(str::to_string);
+
+        let (locale, key) = if let Some((dir, rest)) = relative_id
+            .split_once('/')
+            .filter(|(dir, _)| self.is_locale_dir(dir))
+        {
+            (dir.to_string(), rest.to_string())
+        } else if let Some((base, suffix)) = relative_id
+            .rsplit_once('.')
+            .filter(|(_, suffix)| self.is_locale_suffix(suffix))
+        {
+            (suffix.to_string(), base.to_string())
+        } else {
+            let locale = string_field("lang").or_else(|| string_field("locale"))?;
+            (locale, relative_id.clone())
+        };
+
+        let key = string_field("translationKey").unwrap_or(key);
+        Some((locale, key, relative_id))
+    }
+
+    /// The collection's locales: the configured ones, or those found on its entries
+    fn locales(&self, files: &[FileEntry]) -> Vec<String> {
+        if !self.configured.is_empty() {
+            return self.configured.clone();
+        }
+        let mut locales: Vec<String> = files.iter().filter_map(|f| f.locale.clone()).collect();
+        locales.sort();
+        locales.dedup();
+        locales
+    }
+
+    fn source_locale(&self, locales: &[String]) -> Option<String> {
+        self.default_locale
+            .clone()
+            .or_else(|| locales.iter().find(|locale| *locale == "en").cloned())
+            .or_else(|| locales.first().cloned())
+    }
+}
+
+/// Fills in missing and outdated translations on the entries of a collection. Does nothing
+/// for collections without any localized entries.
+pub fn link_translations(files: &mut [FileEntry], layout: &LocaleLayout) {
+    if files.iter().all(|file| file.locale.is_none()) {
+        return;
+    }
+
+    let locales = layout.locales(files);
+    let Some(source_locale) = layout.source_locale(&locales) else {
+        return;
+    };
+
+    // In a localized collection, entries without a locale are in the source locale
+    for file in files.iter_mut().filter(|file| file.locale.is_none()) {
+        file.locale = Some(source_locale.clone());
+        file.translation_key = Some(file.name.clone());
+    }
+
+    let mut groups: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, file) in files.iter().enumerate() {
+        if let Some(key) = &file.translation_key {
+            groups.entry(key.clone()).or_default().push(index);
+        }
+    }
+
+    for indices in groups.values() {
+        let present: Vec<&str> = indices
+            .iter()
+            .filter_map(|&i| files[i].locale.as_deref())
+            .collect();
+        let missing: Vec<String> = locales
+            .iter()
+            .filter(|locale| !present.contains(&locale.as_str()))
+            .cloned()
+            .collect();
+        let source_modified = indices
+            .iter()
+            .find(|&&i| files[i].locale.as_deref() == Some(source_locale.as_str()))
+            .and_then(|&i| files[i].last_modified);
+
+        for &i in indices {
+            let file = &mut files[i];
+            file.missing_translations = missing.clone();
+            file.outdated_translation = file.locale.as_deref() != Some(source_locale.as_str())
+                && matches!(
+                    (file.last_modified, source_modified),
+                    (Some(modified), Some(source)) if modified < source
+                );
+        }
+    }
+}
+
+/// Reports, for every entry in a collection, which translations exist, which are missing
+/// and which are older than the source-locale entry
+#[tauri::command]
+pub async fn get_translation_status(
+    collection_path: String,
+    ignore_patterns: Option<Vec<String>>,
+) -> Result<TranslationStatus, String> {
+    let files = scan_collection_files(collection_path.clone(), ignore_patterns).await?;
+    let layout = LocaleLayout::detect(
+        Path::new(&collection_path),
+        &find_project_root(Path::new(&collection_path)),
+    );
+
+    let locales = layout.locales(&files);
+    let source_locale = layout.source_locale(&locales);
+
+    let mut groups: BTreeMap<String, Vec<Translation>> = BTreeMap::new();
+    for file in files {
+        let (Some(key), Some(locale)) = (file.translation_key, file.locale) else {
+            continue;
+        };
+        groups.entry(key).or_default().push(Translation {
+            locale,
+            path: file.path,
+            last_modified: file.last_modified,
+            outdated: file.outdated_translation,
+        });
+    }
+
+    let entries = groups
+        .into_iter()
+        .map(|(key, mut translations)| {
+            translations.sort_by_key(|t| locales.iter().position(|l| *l == t.locale));
+            TranslationGroup {
+                missing: locales
+                    .iter()
+                    .filter(|locale| !translations.iter().any(|t| t.locale == **locale))
+                    .cloned()
+                    .collect(),
+                outdated: translations
+                    .iter()
+                    .filter(|t| t.outdated)
+                    .map(|t| t.locale.clone())
+                    .collect(),
+                key,
+                translations,
+            }
+        })
+        .collect();
+
+    Ok(TranslationStatus {
+        locales,
+        source_locale,
+        entries,
+    })
+}
+
+/// Without a config, a filename suffix only counts as a locale when the same entry exists
+/// in another locale (`intro.en.md` and `intro.fr.md`), so `vue.js.md` stays a plain entry
+fn detect_locale_suffixes(stems: &[String]) -> Vec<String> {
+    let mut by_base: HashMap<&str, Vec<&str>> = HashMap::new();
+    for stem in stems {
+        if let Some((base, suffix)) = stem.rsplit_once('.').filter(|(_, s)| is_locale_code(s)) {
+            by_base.entry(base).or_default().push(suffix);
+        }
+    }
+
+    let mut suffixes: Vec<String> = by_base
+        .into_values()
+        .filter(|suffixes| suffixes.len() >= 2)
+        .flatten()
+        .map(str::to_string)
+        .collect();
+    suffixes.sort();
+    suffixes.dedup();
+    suffixes
+}
+
+/// `en`, `de`, `pt-br`, `zh-Hans`
+fn is_locale_code(name: &str) -> bool {
+    let mut parts = name.split('-');
+    let language = parts.next().unwrap_or_default();
+    let region = parts.next();
+
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && region.is_none_or(|region| {
+            (2..=4).contains(&region.len()) && region.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+        && parts.next().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::time::{Duration, SystemTime};
+    use tempfile::TempDir;
+
+    fn write_entry(path: &Path, content: &str, age_secs: u64) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+        let modified = SystemTime::now() - Duration::from_secs(age_secs);
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_file_locale() {
+        let layout = LocaleLayout {
+            locale_dirs: vec!["de".to_string(), "en".to_string()],
+            locale_suffixes: vec!["fr".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            layout.file_locale(Path::new("de/guides/intro.md"), None),
+            Some((
+                "de".to_string(),
+                "guides/intro".to_string(),
+                "de/guides/intro".to_string()
+            ))
+        );
+        assert_eq!(
+            layout.file_locale(Path::new("intro.fr.mdx"), None),
+            Some((
+                "fr".to_string(),
+                "intro".to_string(),
+                "intro.fr".to_string()
+            ))
+        );
+
+        let frontmatter = HashMap::from([
+            ("lang".to_string(), Value::from("es")),
+            ("translationKey".to_string(), Value::from("intro")),
+        ]);
+        assert_eq!(
+            layout.file_locale(Path::new("introduccion.md"), Some(&frontmatter)),
+            Some((
+                "es".to_string(),
+                "intro".to_string(),
+                "introduccion".to_string()
+            ))
+        );
+
+        assert_eq!(layout.file_locale(Path::new("intro.md"), None), None);
+        assert!(is_locale_code("pt-br"));
+        assert!(!is_locale_code("images"));
+    }
+
+    #[test]
+    fn test_dotted_file_names_are_not_locales() {
+        let temp_dir = TempDir::new().unwrap();
+        let collection = temp_dir.path().join("src/content/blog");
+        for name in [
+            "vue.js.md",
+            "node.io.md",
+            "intro.en.md",
+            "intro.fr.md",
+            "about.fr.md",
+        ] {
+            write_entry(&collection.join(name), "---\ntitle: Post\n---\n", 0);
+        }
+
+        let layout = LocaleLayout::detect(&collection, temp_dir.path());
+        assert_eq!(layout.file_locale(Path::new("vue.js.md"), None), None);
+        assert_eq!(layout.file_locale(Path::new("node.io.md"), None), None);
+        assert_eq!(
+            layout
+                .file_locale(Path::new("about.fr.md"), None)
+                .map(|(locale, key, _)| (locale, key)),
+            Some(("fr".to_string(), "about".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_translation_status() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("astro.config.mjs"),
+            "export default defineConfig({ i18n: { defaultLocale: 'en', locales: ['en', 'de', 'fr'] } })",
+        )
+        .unwrap();
+        let docs = root.join("src/content/docs");
+        write_entry(&docs.join("en/intro.md"), "# Intro", 10);
+        write_entry(&docs.join("de/intro.md"), "# Einführung", 100);
+        write_entry(&docs.join("fr/intro.md"), "# Introduction", 5);
+        write_entry(&docs.join("en/guides/setup.md"), "# Setup", 10);
+        // Not a locale, so not scanned below the top level
+        write_entry(&docs.join("drafts/idea.md"), "# Idea", 10);
+
+        let files = scan_collection_files(docs.to_string_lossy().to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(files.len(), 4);
+        let german = files.iter().find(|f| f.id == "docs/de/intro").unwrap();
+        assert_eq!(german.locale.as_deref(), Some("de"));
+        assert!(german.outdated_translation);
+
+        let status = get_translation_status(docs.to_string_lossy().to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(status.locales, vec!["en", "de", "fr"]);
+        assert_eq!(status.source_locale.as_deref(), Some("en"));
+
+        let keys: Vec<&str> = status.entries.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, vec!["guides/setup", "intro"]);
+        assert_eq!(status.entries[0].missing, vec!["de", "fr"]);
+        assert!(status.entries[1].missing.is_empty());
+        assert_eq!(status.entries[1].outdated, vec!["de"]);
+    }
+}
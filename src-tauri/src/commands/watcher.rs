@@ -1,49 +1,182 @@
-use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use crate::astro_config::load_astro_project_config;
+use crate::commands::mdx_components::{component_framework, scan_mdx_components};
+use crate::commands::project::scan_project_with_content_dir;
+use crate::commands::settings::{MAX_POLL_INTERVAL_MS, MIN_POLL_INTERVAL_MS};
+use crate::commands::windows::{
+    emit_to_project, set_window_project, window_project, windows_for_project,
+};
+use crate::network_fs::network_filesystem;
+use crate::project_ignore::{project_walker, IgnoreRules};
+use log::{info, warn};
+use notify::event::{MetadataKind, ModifyKind, RenameMode};
+use notify::{Config, Event, EventKind, PollWatcher, RecursiveMode, Watcher};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::path::PathBuf;
-use std::sync::mpsc;
-use std::sync::{Arc, Mutex};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
-use tauri::{AppHandle, Emitter, Manager, State};
+use tauri::{AppHandle, Manager, State};
 
 // Global watcher storage
-type WatcherMap = Arc<Mutex<HashMap<String, RecommendedWatcher>>>;
+type WatcherMap = Arc<Mutex<HashMap<String, Box<dyn Watcher + Send>>>>;
+
+/// How long the watcher waits for a burst of events to settle before emitting
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(500);
+
+const DEFAULT_POLL_INTERVAL_MS: u64 = 2000;
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatcherMode {
+    /// Native where it works, polling on network filesystems or if native watching fails
+    Auto,
+    Native,
+    Poll,
+}
+
+/// The watcher actually running for a project, reported back to the frontend
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WatcherInfo {
+    pub mode: WatcherMode,
+    pub poll_interval_ms: Option<u64>,
+    pub fallback_reason: Option<String>, // Why polling was chosen in auto mode
+}
+
+/// Changes the app made itself, keyed by canonical path, so the watcher doesn't echo them
+/// back to the frontend. `Some(hash)` is the content we wrote, `None` an expected removal.
+static OWN_CHANGES: OnceLock<Mutex<HashMap<PathBuf, Option<String>>>> = OnceLock::new();
+
+fn own_changes() -> &'static Mutex<HashMap<PathBuf, Option<String>>> {
+    OWN_CHANGES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Watcher events may use a different form of the path (symlinks, `/private/var` on macOS).
+/// Only the parent is canonicalized, since the file itself may not exist (yet or any more).
+fn tracking_key(path: &Path) -> PathBuf {
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => parent
+            .canonicalize()
+            .map(|parent| parent.join(name))
+            .unwrap_or_else(|_| path.to_path_buf()),
+        _ => path.to_path_buf(),
+    }
+}
+
+pub fn content_hash(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Writes a file and records it as the app's own change
+///
+/// The hash is recorded before writing so the watcher can never see the write first.
+pub fn write_tracked(path: &Path, content: impl AsRef<[u8]>) -> std::io::Result<()> {
+    let content = content.as_ref();
+    own_changes()
+        .lock()
+        .unwrap()
+        .insert(tracking_key(path), Some(content_hash(content)));
+    std::fs::write(path, content)
+}
+
+pub fn record_own_removal(path: &Path) {
+    own_changes()
+        .lock()
+        .unwrap()
+        .insert(tracking_key(path), None);
+}
+
+/// Records both sides of a rename the app is about to perform
+pub fn record_own_rename(from: &Path, to: &Path) {
+    let Ok(content) = std::fs::read(from) else {
+        return;
+    };
+    let mut changes = own_changes().lock().unwrap();
+    changes.insert(tracking_key(from), None);
+    changes.insert(tracking_key(to), Some(content_hash(&content)));
+}
+
+/// Drops recorded changes for an operation that failed, so they can't hide real changes
+pub fn forget_own_changes(paths: &[&Path]) {
+    let mut changes = own_changes().lock().unwrap();
+    for path in paths {
+        changes.remove(&tracking_key(path));
+    }
+}
+
+/// Whether a settled change matches something the app did itself.
+/// Matching removals are consumed; content hashes stay until the file changes again,
+/// since a single save can produce several events.
+fn is_own_change(path: &Path, kind: FileChangeKind, hash: Option<&str>) -> bool {
+    let mut changes = own_changes().lock().unwrap();
+    let key = tracking_key(path);
+
+    match (changes.get(&key), kind) {
+        (Some(None), FileChangeKind::Removed) => {
+            changes.remove(&key);
+            true
+        }
+        (Some(Some(recorded)), _) if hash == Some(recorded.as_str()) => true,
+        (Some(_), _) => {
+            // The file changed after our write, so this one is external
+            changes.remove(&key);
+            false
+        }
+        (None, _) => false,
+    }
+}
 
 #[tauri::command]
-pub async fn start_watching_project(app: AppHandle, project_path: String) -> Result<(), String> {
-    start_watching_project_with_content_dir(app, project_path, None).await
+pub async fn start_watching_project(
+    app: AppHandle,
+    window: tauri::Window,
+    project_path: String,
+) -> Result<WatcherInfo, String> {
+    start_watching_project_with_content_dir(app, window, project_path, None, None, None, None, None)
+        .await
 }
 
+/// Starts watching a project for the calling window, which then owns the project:
+/// only that window receives its change events.
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn start_watching_project_with_content_dir(
     app: AppHandle,
+    window: tauri::Window,
     project_path: String,
     content_directory: Option<String>,
-) -> Result<(), String> {
+    mdx_directory: Option<String>,
+    ignore_patterns: Option<Vec<String>>,
+    watcher_mode: Option<WatcherMode>,
+    poll_interval_ms: Option<u64>,
+) -> Result<WatcherInfo, String> {
     let (tx, rx) = mpsc::channel();
 
-    let mut watcher = notify::recommended_watcher(move |result| match result {
-        Ok(event) => {
-            if let Err(e) = tx.send(event) {
-                eprintln!("Failed to send file event: {e}");
-            }
-        }
-        Err(e) => eprintln!("Watch error: {e:?}"),
-    })
-    .map_err(|e| format!("Failed to create watcher: {e}"))?;
-
-    // Watch the content directory specifically (use override if provided)
-    let content_path = if let Some(content_dir) = content_directory {
-        PathBuf::from(&project_path).join(content_dir)
-    } else {
-        PathBuf::from(&project_path).join("src").join("content")
-    };
+    let targets = WatchTargets::new(
+        Path::new(&project_path),
+        content_directory.as_deref(),
+        mdx_directory.as_deref(),
+        ignore_patterns.unwrap_or_default(),
+    );
+    // Identities of existing files, so renames reported as remove + create can be paired
+    let mut identities = FileIdentities::scan(&targets);
 
-    if content_path.exists() {
-        watcher
-            .watch(&content_path, RecursiveMode::Recursive)
-            .map_err(|e| format!("Failed to watch directory: {e}"))?;
-    }
+    let (watcher, watcher_info) = create_watcher(
+        &targets,
+        tx,
+        watcher_mode.unwrap_or(WatcherMode::Auto),
+        // Settings are validated, but the command can also be called with any value
+        poll_interval_ms
+            .unwrap_or(DEFAULT_POLL_INTERVAL_MS)
+            .clamp(MIN_POLL_INTERVAL_MS, MAX_POLL_INTERVAL_MS),
+    )?;
+    info!(
+        "Astro Editor [WATCHER] Watching {project_path} using {:?} mode",
+        watcher_info.mode
+    );
 
     // Store the watcher so it doesn't get dropped
     let watcher_map: State<WatcherMap> = app.state();
@@ -52,62 +185,595 @@ pub async fn start_watching_project_with_content_dir(
         watchers.insert(project_path.clone(), watcher);
     }
 
-    // Handle events in a separate thread
-    let app_handle = app.clone();
-    tokio::spawn(async move {
-        let mut event_buffer = Vec::new();
-        let mut last_event_time = std::time::Instant::now();
+    // A window switching projects no longer needs its previous project's watcher
+    let previous_project = set_window_project(&app, window.label(), Some(project_path.clone()));
+    if let Some(previous) = previous_project.filter(|previous| *previous != project_path) {
+        release_project_watcher(&app, &previous);
+    }
 
-        while let Ok(event) = rx.recv() {
-            event_buffer.push(event);
+    // Handle events in a separate thread. The loop ends when the watcher is dropped
+    // (which closes the channel), flushing anything still pending.
+    let app_handle = app.clone();
+    std::thread::spawn(move || {
+        let mut pending = PendingChanges::default();
 
-            // Debounce events - wait 500ms after last event before processing
-            if last_event_time.elapsed() > Duration::from_millis(500) {
-                process_events(&app_handle, &mut event_buffer).await;
-                event_buffer.clear();
+        loop {
+            match rx.recv_timeout(DEBOUNCE_DURATION) {
+                Ok(event) => pending.record(&targets, &event),
+                Err(RecvTimeoutError::Timeout) => {
+                    // Nothing new for the debounce window, so the burst is over
+                    if !pending.is_empty() {
+                        emit_changes(&app_handle, &targets, &mut pending, &mut identities);
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    emit_changes(&app_handle, &targets, &mut pending, &mut identities);
+                    break;
+                }
             }
-            last_event_time = std::time::Instant::now();
         }
     });
 
+    Ok(watcher_info)
+}
+
+/// Creates a watcher in the requested mode, falling back to polling in auto mode
+fn create_watcher(
+    targets: &WatchTargets,
+    tx: Sender<Event>,
+    mode: WatcherMode,
+    poll_interval_ms: u64,
+) -> Result<(Box<dyn Watcher + Send>, WatcherInfo), String> {
+    let mut fallback_reason = None;
+
+    if mode == WatcherMode::Auto {
+        if let Some(fs_type) = network_filesystem(&targets.project_dir) {
+            fallback_reason = Some(format!("Project is on a network filesystem ({fs_type})"));
+        }
+    }
+
+    if mode == WatcherMode::Native || (mode == WatcherMode::Auto && fallback_reason.is_none()) {
+        let native = notify::recommended_watcher(event_handler(tx.clone()))
+            .and_then(|mut watcher| watch_targets(&mut watcher, targets).map(|()| watcher));
+
+        match native {
+            Ok(watcher) => {
+                let info = WatcherInfo {
+                    mode: WatcherMode::Native,
+                    poll_interval_ms: None,
+                    fallback_reason: None,
+                };
+                return Ok((Box::new(watcher), info));
+            }
+            Err(e) if mode == WatcherMode::Native => {
+                return Err(format!("Failed to create watcher: {e}"));
+            }
+            Err(e) => {
+                warn!(
+                    "Astro Editor [WATCHER] Native watching failed, falling back to polling: {e}"
+                );
+                fallback_reason = Some(format!("Native watching failed: {e}"));
+            }
+        }
+    }
+
+    let config = Config::default().with_poll_interval(Duration::from_millis(poll_interval_ms));
+    let mut watcher = PollWatcher::new(event_handler(tx), config)
+        .map_err(|e| format!("Failed to create watcher: {e}"))?;
+    watch_targets(&mut watcher, targets).map_err(|e| format!("Failed to watch directory: {e}"))?;
+
+    let info = WatcherInfo {
+        mode: WatcherMode::Poll,
+        poll_interval_ms: Some(poll_interval_ms),
+        fallback_reason,
+    };
+    Ok((Box::new(watcher), info))
+}
+
+fn event_handler(tx: Sender<Event>) -> impl FnMut(notify::Result<Event>) + Send + 'static {
+    move |result| match result {
+        Ok(event) => {
+            if let Err(e) = tx.send(event) {
+                eprintln!("Failed to send file event: {e}");
+            }
+        }
+        Err(e) => eprintln!("Watch error: {e:?}"),
+    }
+}
+
+fn watch_targets(watcher: &mut dyn Watcher, targets: &WatchTargets) -> notify::Result<()> {
+    if targets.content_dir.exists() {
+        watcher.watch(&targets.content_dir, RecursiveMode::Recursive)?;
+    }
+    if targets.mdx_dir.exists() {
+        watcher.watch(&targets.mdx_dir, RecursiveMode::Recursive)?;
+    }
+    // Config files are watched via their directories so that editors which save by
+    // replacing the file don't break the watch
+    for dir in targets.config_dirs() {
+        if dir.exists() {
+            watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+        }
+    }
     Ok(())
 }
 
 #[tauri::command]
-pub async fn stop_watching_project(app: AppHandle, project_path: String) -> Result<(), String> {
+pub async fn stop_watching_project(
+    app: AppHandle,
+    window: tauri::Window,
+    project_path: String,
+) -> Result<(), String> {
+    {
+        let watcher_map: State<WatcherMap> = app.state();
+        if !watcher_map.lock().unwrap().contains_key(&project_path) {
+            return Err("No watcher found for this project".to_string());
+        }
+    }
+
+    // This window is done with the project, but others may still have it open
+    if window_project(&app, window.label()).as_deref() == Some(project_path.as_str()) {
+        set_window_project(&app, window.label(), None);
+    }
+    release_project_watcher(&app, &project_path);
+    Ok(())
+}
+
+/// Stops a project's watcher once no window has the project open any more
+pub fn release_project_watcher(app: &AppHandle, project_path: &str) {
+    if !windows_for_project(app, project_path).is_empty() {
+        return;
+    }
+
     let watcher_map: State<WatcherMap> = app.state();
-    let mut watchers = watcher_map.lock().unwrap();
-
-    if watchers.remove(&project_path).is_some() {
-        Ok(())
-    } else {
-        Err("No watcher found for this project".to_string())
-    }
-}
-
-async fn process_events(app: &AppHandle, events: &mut [Event]) {
-    for event in events.iter() {
-        match &event.kind {
-            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_) => {
-                // Check if it's a markdown file
-                for path in &event.paths {
-                    if let Some(extension) = path.extension() {
-                        if matches!(extension.to_str(), Some("md") | Some("mdx")) {
-                            // Emit event to frontend
-                            if let Err(e) = app.emit(
-                                "file-changed",
-                                FileChangeEvent {
-                                    path: path.to_string_lossy().to_string(),
-                                    kind: format!("{:?}", event.kind),
-                                },
-                            ) {
-                                eprintln!("Failed to emit file change event: {e}");
-                            }
-                        }
-                    }
+    if watcher_map.lock().unwrap().remove(project_path).is_some() {
+        info!("Astro Editor [WATCHER] Stopped watching {project_path}");
+    }
+}
+
+/// The paths a project watcher cares about
+struct WatchTargets {
+    project_dir: PathBuf,
+    src_dir: PathBuf,
+    content_dir: PathBuf,
+    mdx_dir: PathBuf,
+    content_directory: Option<String>,
+    mdx_directory: Option<String>,
+    ignore_patterns: Vec<String>,
+    ignore: IgnoreRules,
+}
+
+impl WatchTargets {
+    fn new(
+        project_path: &Path,
+        content_directory: Option<&str>,
+        mdx_directory: Option<&str>,
+        ignore_patterns: Vec<String>,
+    ) -> Self {
+        // Defaults follow srcDir from astro.config
+        let astro_config = load_astro_project_config(project_path);
+        let content_dir = match content_directory {
+            Some(dir) => project_path.join(dir),
+            None => project_path.join(astro_config.content_directory()),
+        };
+        let mdx_dir = match mdx_directory {
+            Some(dir) => project_path.join(dir),
+            None => project_path.join(astro_config.mdx_directory()),
+        };
+
+        Self {
+            ignore: IgnoreRules::load(project_path, &ignore_patterns),
+            ignore_patterns,
+            project_dir: project_path.to_path_buf(),
+            src_dir: astro_config.src_dir_path(project_path),
+            content_dir,
+            mdx_dir,
+            content_directory: content_directory.map(str::to_string),
+            mdx_directory: mdx_directory.map(str::to_string),
+        }
+    }
+
+    fn is_content_file(&self, path: &Path) -> bool {
+        is_markdown_file(path)
+            && path.starts_with(&self.content_dir)
+            && !self.ignore.is_ignored(path, false)
+    }
+
+    /// Directories holding `astro.config.*` and `content.config.ts`
+    fn config_dirs(&self) -> [PathBuf; 2] {
+        [self.project_dir.clone(), self.src_dir.clone()]
+    }
+
+    /// Whether a path is one of the files that define collections and their schemas
+    fn is_config_file(&self, path: &Path) -> bool {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            return false;
+        };
+        let Some(parent) = path.parent() else {
+            return false;
+        };
+
+        let is_astro_config = name.starts_with("astro.config.") && parent == self.project_dir;
+        let is_content_config = name.starts_with("content.config.") && parent == self.src_dir;
+        // Legacy location, inside the content directory
+        let is_legacy_config = name == "config.ts" && parent == self.src_dir.join("content");
+
+        is_astro_config || is_content_config || is_legacy_config
+    }
+
+    fn is_component_file(&self, path: &Path) -> bool {
+        path.starts_with(&self.mdx_dir)
+            && component_framework(path).is_some()
+            && !self.ignore.is_ignored(path, false)
+    }
+}
+
+/// Changes accumulated during a burst of events
+#[derive(Default)]
+struct PendingChanges {
+    files: HashMap<PathBuf, FileChangeKind>,
+    renames: Vec<(PathBuf, PathBuf)>,
+    /// "Renamed from" halves waiting for their "renamed to", keyed by notify's tracker cookie
+    rename_sources: HashMap<usize, PathBuf>,
+    /// A "renamed from" without a cookie (Windows), paired with the next "renamed to"
+    untracked_rename_source: Option<PathBuf>,
+    schema_changed: bool,
+    components_changed: bool,
+}
+
+impl PendingChanges {
+    fn is_empty(&self) -> bool {
+        self.files.is_empty()
+            && self.renames.is_empty()
+            && self.rename_sources.is_empty()
+            && self.untracked_rename_source.is_none()
+            && !self.schema_changed
+            && !self.components_changed
+    }
+
+    /// Adds a notify event, coalescing with earlier events for the same path
+    fn record(&mut self, targets: &WatchTargets, event: &Event) {
+        for path in &event.paths {
+            if targets.is_config_file(path) {
+                self.schema_changed = true;
+            } else if targets.is_component_file(path) {
+                self.components_changed = true;
+            }
+        }
+
+        if let EventKind::Modify(ModifyKind::Name(mode)) = event.kind {
+            self.record_rename(targets, mode, event);
+            return;
+        }
+
+        let Some(kind) = FileChangeKind::from_event_kind(&event.kind) else {
+            return;
+        };
+        for path in &event.paths {
+            self.record_file(targets, path, kind);
+        }
+    }
+
+    fn record_file(&mut self, targets: &WatchTargets, path: &Path, kind: FileChangeKind) {
+        if !targets.is_content_file(path) {
+            return;
+        }
+        match coalesce_change(self.files.get(path).copied(), kind) {
+            Some(kind) => self.files.insert(path.to_path_buf(), kind),
+            None => self.files.remove(path),
+        };
+    }
+
+    fn record_rename(&mut self, targets: &WatchTargets, mode: RenameMode, event: &Event) {
+        match (mode, event.paths.as_slice()) {
+            (RenameMode::Both, [from, to]) => self.record_rename_pair(targets, from, to),
+            (RenameMode::From, [from]) => match event.tracker() {
+                Some(tracker) => {
+                    self.rename_sources.insert(tracker, from.clone());
+                }
+                None => self.untracked_rename_source = Some(from.clone()),
+            },
+            (RenameMode::To, [to]) => {
+                let from = match event.tracker() {
+                    Some(tracker) => self.rename_sources.remove(&tracker),
+                    None => self.untracked_rename_source.take(),
+                };
+                match from {
+                    Some(from) => self.record_rename_pair(targets, &from, to),
+                    None => self.record_file(targets, to, FileChangeKind::Created),
+                }
+            }
+            // macOS doesn't say which side of a rename a path is, so check the disk.
+            // These are paired up by identity when the burst is flushed.
+            (_, paths) => {
+                for path in paths {
+                    let kind = if path.exists() {
+                        FileChangeKind::Created
+                    } else {
+                        FileChangeKind::Removed
+                    };
+                    self.record_file(targets, path, kind);
+                }
+            }
+        }
+    }
+
+    fn record_rename_pair(&mut self, targets: &WatchTargets, from: &Path, to: &Path) {
+        match (targets.is_content_file(from), targets.is_content_file(to)) {
+            (true, true) => self.renames.push((from.to_path_buf(), to.to_path_buf())),
+            // Moved out of (or into) the content directory, or to a non-Markdown name
+            (true, false) => self.record_file(targets, from, FileChangeKind::Removed),
+            (false, true) => self.record_file(targets, to, FileChangeKind::Created),
+            (false, false) => {}
+        }
+    }
+
+    /// Turns unpaired rename halves into removals and pairs removals with creations of
+    /// the same file, returning every rename in the burst
+    fn take_renames(
+        &mut self,
+        targets: &WatchTargets,
+        identities: &FileIdentities,
+    ) -> Vec<(PathBuf, PathBuf)> {
+        let unpaired: Vec<PathBuf> = self
+            .rename_sources
+            .drain()
+            .map(|(_, path)| path)
+            .chain(self.untracked_rename_source.take())
+            .collect();
+        for path in unpaired {
+            self.record_file(targets, &path, FileChangeKind::Removed);
+        }
+
+        let mut renames = std::mem::take(&mut self.renames);
+
+        let removed: Vec<PathBuf> = self
+            .files
+            .iter()
+            .filter(|(_, kind)| **kind == FileChangeKind::Removed)
+            .map(|(path, _)| path.clone())
+            .collect();
+        let mut created: Vec<(PathBuf, FileIdentity)> = self
+            .files
+            .iter()
+            .filter(|(_, kind)| **kind == FileChangeKind::Created)
+            .filter_map(|(path, _)| Some((path.clone(), FileIdentity::read(path)?)))
+            .collect();
+
+        for from in removed {
+            let Some(old_identity) = identities.get(&from) else {
+                continue;
+            };
+            if let Some(index) = created
+                .iter()
+                .position(|(_, identity)| identity.is_same_file(old_identity))
+            {
+                let (to, _) = created.swap_remove(index);
+                self.files.remove(&from);
+                self.files.remove(&to);
+                renames.push((from, to));
+            }
+        }
+
+        renames
+    }
+}
+
+/// What identifies a file across a rename: its inode where available, otherwise its content
+#[derive(Debug, Clone, PartialEq)]
+struct FileIdentity {
+    inode: Option<u64>,
+    hash: String,
+}
+
+impl FileIdentity {
+    fn read(path: &Path) -> Option<Self> {
+        let content = std::fs::read(path).ok()?;
+        Some(Self {
+            inode: inode(path),
+            hash: content_hash(&content),
+        })
+    }
+
+    fn is_same_file(&self, other: &FileIdentity) -> bool {
+        match (self.inode, other.inode) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.hash == other.hash,
+        }
+    }
+}
+
+#[cfg(unix)]
+fn inode(path: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    std::fs::metadata(path).ok().map(|metadata| metadata.ino())
+}
+
+#[cfg(not(unix))]
+fn inode(_path: &Path) -> Option<u64> {
+    None
+}
+
+/// Last known identity of each content file, kept up to date as changes are emitted
+#[derive(Default)]
+struct FileIdentities(HashMap<PathBuf, FileIdentity>);
+
+impl FileIdentities {
+    fn scan(targets: &WatchTargets) -> Self {
+        let files = project_walker(
+            &targets.content_dir,
+            &targets.project_dir,
+            &targets.ignore_patterns,
+        )
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry.file_type().is_some_and(|t| t.is_file()) && is_markdown_file(entry.path())
+        })
+        .filter_map(|entry| {
+            let identity = FileIdentity::read(entry.path())?;
+            Some((entry.into_path(), identity))
+        })
+        .collect();
+        Self(files)
+    }
+
+    fn get(&self, path: &Path) -> Option<&FileIdentity> {
+        self.0.get(path)
+    }
+
+    fn update(&mut self, path: &Path, identity: Option<FileIdentity>) {
+        match identity {
+            Some(identity) => self.0.insert(path.to_path_buf(), identity),
+            None => self.0.remove(path),
+        };
+    }
+}
+
+fn emit_changes(
+    app: &AppHandle,
+    targets: &WatchTargets,
+    pending: &mut PendingChanges,
+    identities: &mut FileIdentities,
+) {
+    let project_path = targets.project_dir.to_string_lossy().to_string();
+
+    // Schema changes go first so the frontend has up-to-date collections for file events
+    if std::mem::take(&mut pending.schema_changed) {
+        let scan =
+            scan_project_with_content_dir(project_path.clone(), targets.content_directory.clone());
+        match tauri::async_runtime::block_on(scan) {
+            Ok(collections) => {
+                info!(
+                    "Astro Editor [WATCHER] Content config changed, reloaded {} collections",
+                    collections.len()
+                );
+                if let Err(e) = emit_to_project(app, &project_path, "schema-changed", collections) {
+                    eprintln!("Failed to emit schema change event: {e}");
                 }
             }
-            _ => {}
+            Err(e) => warn!("Astro Editor [WATCHER] Failed to reload collections: {e}"),
+        }
+    }
+
+    if std::mem::take(&mut pending.components_changed) {
+        let scan = scan_mdx_components(
+            project_path.clone(),
+            targets.mdx_directory.clone(),
+            Some(targets.ignore_patterns.clone()),
+        );
+        match tauri::async_runtime::block_on(scan) {
+            Ok(components) => {
+                if let Err(e) =
+                    emit_to_project(app, &project_path, "components-changed", components)
+                {
+                    eprintln!("Failed to emit components change event: {e}");
+                }
+            }
+            Err(e) => warn!("Astro Editor [WATCHER] Failed to rescan MDX components: {e}"),
+        }
+    }
+
+    // Renames go before other file changes so the editor can follow the file first
+    for (from, to) in pending.take_renames(targets, identities) {
+        let identity = FileIdentity::read(&to);
+        let hash = identity.as_ref().map(|identity| identity.hash.clone());
+        identities.update(&from, None);
+        identities.update(&to, identity);
+
+        // Evaluate both sides so the recorded changes are consumed together
+        let own_removal = is_own_change(&from, FileChangeKind::Removed, None);
+        let own_creation = is_own_change(&to, FileChangeKind::Created, hash.as_deref());
+        if own_removal && own_creation {
+            continue;
+        }
+
+        if let Err(e) = emit_to_project(
+            app,
+            &project_path,
+            "file-renamed",
+            FileRenameEvent {
+                from: from.to_string_lossy().to_string(),
+                to: to.to_string_lossy().to_string(),
+                content_hash: hash,
+            },
+        ) {
+            eprintln!("Failed to emit file rename event: {e}");
+        }
+    }
+
+    for (path, kind) in pending.files.drain() {
+        let identity = match kind {
+            FileChangeKind::Removed => None,
+            _ => FileIdentity::read(&path),
+        };
+        let hash = identity.as_ref().map(|identity| identity.hash.clone());
+        identities.update(&path, identity);
+
+        if is_own_change(&path, kind, hash.as_deref()) {
+            continue;
+        }
+
+        if let Err(e) = emit_to_project(
+            app,
+            &project_path,
+            "file-changed",
+            FileChangeEvent {
+                path: path.to_string_lossy().to_string(),
+                kind,
+                content_hash: hash,
+            },
+        ) {
+            eprintln!("Failed to emit file change event: {e}");
+        }
+    }
+}
+
+fn is_markdown_file(path: &std::path::Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("md") | Some("mdx")
+    )
+}
+
+/// Combines the change already pending for a path with a new one.
+/// Returns `None` when the changes cancel out (a file created and removed within one burst).
+fn coalesce_change(
+    existing: Option<FileChangeKind>,
+    new: FileChangeKind,
+) -> Option<FileChangeKind> {
+    use FileChangeKind::*;
+
+    match (existing, new) {
+        (None, new) => Some(new),
+        (Some(Created), Modified) => Some(Created),
+        (Some(Created), Removed) => None,
+        (Some(Removed), Created) | (Some(Removed), Modified) => Some(Modified),
+        (Some(_), new) => Some(new),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChangeKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+impl FileChangeKind {
+    fn from_event_kind(kind: &EventKind) -> Option<Self> {
+        match kind {
+            EventKind::Create(_) => Some(Self::Created),
+            // The poll watcher reports content changes as a new modification time
+            EventKind::Modify(ModifyKind::Metadata(MetadataKind::WriteTime)) => {
+                Some(Self::Modified)
+            }
+            // Other metadata changes (permissions, access times) aren't content changes
+            EventKind::Modify(ModifyKind::Metadata(_)) => None,
+            EventKind::Modify(_) => Some(Self::Modified),
+            EventKind::Remove(_) => Some(Self::Removed),
+            _ => None,
         }
     }
 }
@@ -115,10 +781,333 @@ async fn process_events(app: &AppHandle, events: &mut [Event]) {
 #[derive(serde::Serialize, Clone)]
 struct FileChangeEvent {
     path: String,
-    kind: String,
+    kind: FileChangeKind,
+    content_hash: Option<String>, // None for removed files
+}
+
+#[derive(serde::Serialize, Clone)]
+struct FileRenameEvent {
+    from: String,
+    to: String,
+    content_hash: Option<String>,
 }
 
 // Initialize the watcher map when the app starts
 pub fn init_watcher_state() -> WatcherMap {
     Arc::new(Mutex::new(HashMap::new()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{CreateKind, DataChange, MetadataKind, RemoveKind};
+
+    fn event(kind: EventKind, path: &str) -> Event {
+        Event::new(kind).add_path(PathBuf::from(path))
+    }
+
+    #[test]
+    fn test_coalesce_change() {
+        use FileChangeKind::*;
+
+        assert_eq!(coalesce_change(None, Modified), Some(Modified));
+        assert_eq!(coalesce_change(Some(Created), Modified), Some(Created));
+        assert_eq!(coalesce_change(Some(Created), Removed), None);
+        assert_eq!(coalesce_change(Some(Removed), Created), Some(Modified));
+        assert_eq!(coalesce_change(Some(Modified), Removed), Some(Removed));
+    }
+
+    #[test]
+    fn test_record_event_coalesces_per_path() {
+        let targets = WatchTargets::new(Path::new("/p"), None, None, vec![]);
+        let mut pending = PendingChanges::default();
+
+        // A file created and then written to is still reported as created
+        pending.record(
+            &targets,
+            &event(
+                EventKind::Create(CreateKind::File),
+                "/p/src/content/post.md",
+            ),
+        );
+        pending.record(
+            &targets,
+            &event(
+                EventKind::Modify(ModifyKind::Data(DataChange::Content)),
+                "/p/src/content/post.md",
+            ),
+        );
+        pending.record(
+            &targets,
+            &event(
+                EventKind::Modify(ModifyKind::Data(DataChange::Content)),
+                "/p/src/content/other.mdx",
+            ),
+        );
+        pending.record(
+            &targets,
+            &event(
+                EventKind::Modify(ModifyKind::Metadata(MetadataKind::Permissions)),
+                "/p/src/content/third.md",
+            ),
+        );
+        pending.record(
+            &targets,
+            &event(
+                EventKind::Create(CreateKind::File),
+                "/p/src/content/image.png",
+            ),
+        );
+
+        assert_eq!(pending.files.len(), 2);
+        assert_eq!(
+            pending.files.get(&PathBuf::from("/p/src/content/post.md")),
+            Some(&FileChangeKind::Created)
+        );
+        assert_eq!(
+            pending
+                .files
+                .get(&PathBuf::from("/p/src/content/other.mdx")),
+            Some(&FileChangeKind::Modified)
+        );
+
+        pending.record(
+            &targets,
+            &event(
+                EventKind::Remove(RemoveKind::File),
+                "/p/src/content/post.md",
+            ),
+        );
+        assert!(!pending
+            .files
+            .contains_key(&PathBuf::from("/p/src/content/post.md")));
+    }
+
+    #[test]
+    fn test_config_and_component_changes_are_flagged() {
+        let targets = WatchTargets::new(Path::new("/p"), None, Some("src/components/mdx"), vec![]);
+        let mut pending = PendingChanges::default();
+        let modify = EventKind::Modify(ModifyKind::Data(DataChange::Content));
+
+        // Unrelated files in the watched project root are ignored
+        pending.record(&targets, &event(modify, "/p/package.json"));
+        assert!(pending.is_empty());
+
+        pending.record(
+            &targets,
+            &event(modify, "/p/src/components/mdx/Callout.astro"),
+        );
+        assert!(pending.components_changed);
+        assert!(!pending.schema_changed);
+
+        pending.record(&targets, &event(modify, "/p/astro.config.mjs"));
+        assert!(pending.schema_changed);
+
+        let mut pending = PendingChanges::default();
+        pending.record(&targets, &event(modify, "/p/src/content.config.ts"));
+        assert!(pending.schema_changed);
+
+        let mut pending = PendingChanges::default();
+        pending.record(&targets, &event(modify, "/p/src/content/config.ts"));
+        assert!(pending.schema_changed);
+        assert!(pending.files.is_empty());
+
+        // Framework components are rescanned too
+        for component in ["Counter.tsx", "Chart.vue", "Toggle.svelte"] {
+            let mut pending = PendingChanges::default();
+            let path = format!("/p/src/components/mdx/{component}");
+            pending.record(&targets, &event(modify, &path));
+            assert!(pending.components_changed, "{component}");
+        }
+    }
+
+    #[test]
+    fn test_targets_follow_astro_src_dir() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(
+            root.join("astro.config.mjs"),
+            "export default defineConfig({ srcDir: './app' })",
+        )
+        .unwrap();
+
+        let targets = WatchTargets::new(root, None, None, vec![]);
+        let mut pending = PendingChanges::default();
+        let modify = EventKind::Modify(ModifyKind::Data(DataChange::Content));
+
+        pending.record(
+            &targets,
+            &event(
+                modify,
+                root.join("app/content/blog/post.md").to_str().unwrap(),
+            ),
+        );
+        assert!(pending
+            .files
+            .contains_key(&root.join("app/content/blog/post.md")));
+
+        pending.record(
+            &targets,
+            &event(
+                modify,
+                root.join("src/content/blog/post.md").to_str().unwrap(),
+            ),
+        );
+        assert_eq!(pending.files.len(), 1);
+
+        pending.record(
+            &targets,
+            &event(modify, root.join("app/content.config.ts").to_str().unwrap()),
+        );
+        assert!(pending.schema_changed);
+    }
+
+    #[test]
+    fn test_own_writes_are_not_echoed() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("post.md");
+
+        write_tracked(&path, "# Saved by the app").unwrap();
+        let hash = content_hash(b"# Saved by the app");
+        assert!(is_own_change(&path, FileChangeKind::Created, Some(&hash)));
+        // Several events from the same save are all suppressed
+        assert!(is_own_change(&path, FileChangeKind::Modified, Some(&hash)));
+
+        // An external edit after our save is reported
+        std::fs::write(&path, "# Edited elsewhere").unwrap();
+        let external_hash = content_hash(b"# Edited elsewhere");
+        assert!(!is_own_change(
+            &path,
+            FileChangeKind::Modified,
+            Some(&external_hash)
+        ));
+
+        record_own_removal(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(is_own_change(&path, FileChangeKind::Removed, None));
+        // The expected removal is consumed
+        assert!(!is_own_change(&path, FileChangeKind::Removed, None));
+    }
+
+    #[test]
+    fn test_rename_events_are_paired() {
+        let targets = WatchTargets::new(Path::new("/p"), None, None, vec![]);
+        let identities = FileIdentities::default();
+        let mut pending = PendingChanges::default();
+        let name = |mode| EventKind::Modify(ModifyKind::Name(mode));
+
+        // inotify: separate halves linked by a tracker cookie
+        pending.record(
+            &targets,
+            &event(name(RenameMode::From), "/p/src/content/blog/old.md").set_tracker(7),
+        );
+        pending.record(
+            &targets,
+            &event(name(RenameMode::To), "/p/src/content/blog/new.md").set_tracker(7),
+        );
+        // Both paths in one event
+        pending.record(
+            &targets,
+            &event(name(RenameMode::Both), "/p/src/content/a.md")
+                .add_path("/p/src/content/b.md".into()),
+        );
+        // Renamed to a non-Markdown name is a removal
+        pending.record(
+            &targets,
+            &event(name(RenameMode::Both), "/p/src/content/c.md")
+                .add_path("/p/src/content/c.md.bak".into()),
+        );
+        // A "from" with no matching "to" was moved out of the watched tree
+        pending.record(
+            &targets,
+            &event(name(RenameMode::From), "/p/src/content/gone.md").set_tracker(8),
+        );
+
+        let renames = pending.take_renames(&targets, &identities);
+        assert_eq!(
+            renames,
+            vec![
+                (
+                    PathBuf::from("/p/src/content/blog/old.md"),
+                    PathBuf::from("/p/src/content/blog/new.md")
+                ),
+                (
+                    PathBuf::from("/p/src/content/a.md"),
+                    PathBuf::from("/p/src/content/b.md")
+                ),
+            ]
+        );
+        assert_eq!(
+            pending.files.get(&PathBuf::from("/p/src/content/c.md")),
+            Some(&FileChangeKind::Removed)
+        );
+        assert_eq!(
+            pending.files.get(&PathBuf::from("/p/src/content/gone.md")),
+            Some(&FileChangeKind::Removed)
+        );
+    }
+
+    #[test]
+    fn test_remove_and_create_of_same_file_is_a_rename() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let content_dir = temp_dir.path().join("src/content/blog");
+        std::fs::create_dir_all(&content_dir).unwrap();
+        let old_path = content_dir.join("old.md");
+        let new_path = content_dir.join("new.md");
+        let other_path = content_dir.join("other.md");
+        std::fs::write(&old_path, "# Moving post").unwrap();
+
+        let targets = WatchTargets::new(temp_dir.path(), None, None, vec![]);
+        let identities = FileIdentities::scan(&targets);
+
+        std::fs::rename(&old_path, &new_path).unwrap();
+        std::fs::write(&other_path, "# Unrelated post").unwrap();
+
+        let mut pending = PendingChanges::default();
+        pending.record(
+            &targets,
+            &Event::new(EventKind::Remove(RemoveKind::File)).add_path(old_path.clone()),
+        );
+        pending.record(
+            &targets,
+            &Event::new(EventKind::Create(CreateKind::File)).add_path(new_path.clone()),
+        );
+        pending.record(
+            &targets,
+            &Event::new(EventKind::Create(CreateKind::File)).add_path(other_path.clone()),
+        );
+
+        let renames = pending.take_renames(&targets, &identities);
+        assert_eq!(renames, vec![(old_path, new_path)]);
+        assert_eq!(pending.files.len(), 1);
+        assert_eq!(
+            pending.files.get(&other_path),
+            Some(&FileChangeKind::Created)
+        );
+    }
+
+    #[test]
+    fn test_ignored_files_are_not_recorded() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join(".gitignore"),
+            "src/content/generated/\n",
+        )
+        .unwrap();
+        let targets = WatchTargets::new(temp_dir.path(), None, None, vec!["*.tmp.md".to_string()]);
+        let content_dir = temp_dir.path().join("src/content");
+        let mut pending = PendingChanges::default();
+
+        for name in ["generated/api.md", "blog/draft.tmp.md", "blog/post.md"] {
+            pending.record(
+                &targets,
+                &Event::new(EventKind::Create(CreateKind::File)).add_path(content_dir.join(name)),
+            );
+        }
+
+        assert_eq!(pending.files.len(), 1);
+        assert!(pending
+            .files
+            .contains_key(&content_dir.join("blog/post.md")));
+    }
+}
@@ -0,0 +1,195 @@
+use log::info;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, WebviewUrl, WebviewWindowBuilder};
+
+/// Prefix for windows opened with `open_project_in_new_window`
+const PROJECT_WINDOW_PREFIX: &str = "project-";
+
+static NEXT_WINDOW_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Increases every time a window gains focus, so the most recent one can be found
+static NEXT_FOCUS_ORDER: AtomicUsize = AtomicUsize::new(1);
+
+/// Per-window state: the project it has open and its menu state
+#[derive(Debug, Clone, Default)]
+pub struct WindowContext {
+    pub project_path: Option<String>,
+    pub format_menu_enabled: bool,
+    /// When the window last gained focus (0 if it never has)
+    pub focus_order: usize,
+}
+
+/// Window label -> context
+pub type WindowMap = Mutex<HashMap<String, WindowContext>>;
+
+pub fn init_window_state() -> WindowMap {
+    Mutex::new(HashMap::new())
+}
+
+/// Returns the project open in the calling window, if any
+#[tauri::command]
+pub async fn get_window_project(
+    app: AppHandle,
+    window: tauri::Window,
+) -> Result<Option<String>, String> {
+    Ok(window_project(&app, window.label()))
+}
+
+/// Opens a project in its own window, or focuses the window that already has it open.
+/// Returns the label of that window.
+#[tauri::command]
+pub async fn open_project_in_new_window(
+    app: AppHandle,
+    project_path: String,
+) -> Result<String, String> {
+    if let Some(label) = windows_for_project(&app, &project_path).into_iter().next() {
+        if let Some(window) = app.get_webview_window(&label) {
+            window
+                .set_focus()
+                .map_err(|e| format!("Failed to focus window: {e}"))?;
+            return Ok(label);
+        }
+    }
+
+    let label = format!(
+        "{PROJECT_WINDOW_PREFIX}{}",
+        NEXT_WINDOW_ID.fetch_add(1, Ordering::Relaxed)
+    );
+
+    // Claim the project before the window loads, so it can ask which project is its own
+    set_window_project(&app, &label, Some(project_path.clone()));
+
+    let window = WebviewWindowBuilder::new(&app, &label, WebviewUrl::default())
+        .title("Astro Editor")
+        .inner_size(1400.0, 900.0)
+        .min_inner_size(1000.0, 700.0)
+        .decorations(false)
+        .transparent(true)
+        .shadow(true)
+        .build()
+        .map_err(|e| {
+            remove_window(&app, &label);
+            format!("Failed to create window: {e}")
+        })?;
+
+    #[cfg(target_os = "macos")]
+    {
+        use window_vibrancy::{apply_vibrancy, NSVisualEffectMaterial};
+        if let Err(e) = apply_vibrancy(&window, NSVisualEffectMaterial::HudWindow, None, Some(12.0))
+        {
+            log::warn!("Astro Editor [WINDOWS] Failed to apply vibrancy: {e}");
+        }
+    }
+    #[cfg(not(target_os = "macos"))]
+    let _ = window;
+
+    info!("Astro Editor [WINDOWS] Opened {project_path} in window {label}");
+    Ok(label)
+}
+
+pub fn window_project(app: &AppHandle, label: &str) -> Option<String> {
+    let windows = app.state::<WindowMap>();
+    let windows = windows.lock().unwrap();
+    windows
+        .get(label)
+        .and_then(|context| context.project_path.clone())
+}
+
+/// Records which project a window has open, returning the project it had before
+pub fn set_window_project(
+    app: &AppHandle,
+    label: &str,
+    project_path: Option<String>,
+) -> Option<String> {
+    let windows = app.state::<WindowMap>();
+    let mut windows = windows.lock().unwrap();
+    let context = windows.entry(label.to_string()).or_default();
+    std::mem::replace(&mut context.project_path, project_path)
+}
+
+/// Forgets a closed window, returning the project it had open
+pub fn remove_window(app: &AppHandle, label: &str) -> Option<String> {
+    let windows = app.state::<WindowMap>();
+    let mut windows = windows.lock().unwrap();
+    windows
+        .remove(label)
+        .and_then(|context| context.project_path)
+}
+
+pub fn windows_for_project(app: &AppHandle, project_path: &str) -> Vec<String> {
+    let windows = app.state::<WindowMap>();
+    let windows = windows.lock().unwrap();
+    windows
+        .iter()
+        .filter(|(_, context)| context.project_path.as_deref() == Some(project_path))
+        .map(|(label, _)| label.clone())
+        .collect()
+}
+
+/// Emits an event only to the windows that have `project_path` open
+pub fn emit_to_project<S: Serialize + Clone>(
+    app: &AppHandle,
+    project_path: &str,
+    event: &str,
+    payload: S,
+) -> Result<(), tauri::Error> {
+    for label in windows_for_project(app, project_path) {
+        app.emit_to(label.as_str(), event, payload.clone())?;
+    }
+    Ok(())
+}
+
+/// Records that a window gained focus
+pub fn record_window_focus(app: &AppHandle, label: &str) {
+    let windows = app.state::<WindowMap>();
+    let mut windows = windows.lock().unwrap();
+    windows.entry(label.to_string()).or_default().focus_order =
+        NEXT_FOCUS_ORDER.fetch_add(1, Ordering::Relaxed);
+}
+
+/// The window menu actions apply to: the focused one, falling back to the most recently
+/// focused window that still exists, then to any open window
+pub fn focused_window_label(app: &AppHandle) -> Option<String> {
+    let open_windows = app.webview_windows();
+    if let Some(label) = open_windows
+        .iter()
+        .find(|(_, window)| window.is_focused().unwrap_or(false))
+        .map(|(label, _)| label.clone())
+    {
+        return Some(label);
+    }
+
+    let windows = app.state::<WindowMap>();
+    let windows = windows.lock().unwrap();
+    windows
+        .iter()
+        .filter(|(label, context)| context.focus_order > 0 && open_windows.contains_key(*label))
+        .max_by_key(|(_, context)| context.focus_order)
+        .map(|(label, _)| label.clone())
+        .or_else(|| open_windows.into_keys().next())
+}
+
+/// Stores a window's format menu state, returning whether it should be shown in the menu
+/// bar now (the menu is shared, so it only reflects the focused window)
+pub fn set_window_format_menu_enabled(app: &AppHandle, label: &str, enabled: bool) -> bool {
+    {
+        let windows = app.state::<WindowMap>();
+        let mut windows = windows.lock().unwrap();
+        windows
+            .entry(label.to_string())
+            .or_default()
+            .format_menu_enabled = enabled;
+    }
+    focused_window_label(app).as_deref() == Some(label)
+}
+
+pub fn window_format_menu_enabled(app: &AppHandle, label: &str) -> bool {
+    let windows = app.state::<WindowMap>();
+    let windows = windows.lock().unwrap();
+    windows
+        .get(label)
+        .is_some_and(|context| context.format_menu_enabled)
+}
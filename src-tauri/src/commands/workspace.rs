@@ -0,0 +1,281 @@
+use crate::astro_config::find_astro_config;
+use crate::commands::project::is_blocked_directory;
+use crate::paths::to_slash_path;
+use ignore::WalkBuilder;
+use log::{debug, info};
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+/// How far below the opened folder to look for Astro sites
+const MAX_SEARCH_DEPTH: usize = 5;
+
+/// Build output and dependency folders that never contain a site worth opening
+const SKIPPED_DIRS: &[&str] = &["node_modules", "dist", "build", "out", "coverage"];
+
+/// An Astro site found inside an opened folder
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AstroSite {
+    pub path: String,
+    pub relative_path: String, // Empty for the opened folder itself
+    pub name: String,          // package.json name, or the folder name
+    pub config_file: String,
+    pub workspace_member: bool,
+}
+
+/// Finds the Astro sites in a folder: the folder itself, workspace packages from
+/// `pnpm-workspace.yaml` or `package.json` workspaces, and any other folder with an
+/// `astro.config.*`. Workspace members are listed first.
+#[tauri::command]
+pub async fn detect_astro_sites(root_path: String) -> Result<Vec<AstroSite>, String> {
+    let root = Path::new(&root_path);
+    if is_blocked_directory(root) {
+        return Err(format!(
+            "Cannot open project in restricted directory: {root_path}"
+        ));
+    }
+    if !root.is_dir() {
+        return Err(format!("Directory not found: {root_path}"));
+    }
+
+    // `!` patterns exclude packages the other patterns include
+    let (excluded, included): (Vec<String>, Vec<String>) = workspace_patterns(root)
+        .into_iter()
+        .partition(|pattern| pattern.starts_with('!'));
+    let included: Vec<Regex> = included.iter().filter_map(|p| glob_regex(p)).collect();
+    let excluded: Vec<Regex> = excluded
+        .iter()
+        .filter_map(|p| glob_regex(&p[1..]))
+        .collect();
+
+    let mut sites: Vec<AstroSite> = WalkBuilder::new(root)
+        .max_depth(Some(MAX_SEARCH_DEPTH))
+        .require_git(false)
+        .git_global(false)
+        .filter_entry(|entry| {
+            let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+            let skipped = entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| SKIPPED_DIRS.contains(&name));
+            is_dir && !skipped && !is_blocked_directory(entry.path())
+        })
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let dir = entry.path();
+            let config_file = find_astro_config(dir)?;
+            let relative_path = to_slash_path(dir.strip_prefix(root).unwrap_or(dir));
+            let workspace_member = included.iter().any(|p| p.is_match(&relative_path))
+                && !excluded.iter().any(|p| p.is_match(&relative_path));
+
+            Some(AstroSite {
+                path: dir.to_string_lossy().to_string(),
+                name: package_name(dir).unwrap_or_else(|| {
+                    dir.file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_else(|| relative_path.clone())
+                }),
+                relative_path,
+                config_file: config_file.to_string_lossy().to_string(),
+                workspace_member,
+            })
+        })
+        .collect();
+
+    sites.sort_by(|a, b| {
+        (
+            !a.relative_path.is_empty(),
+            !a.workspace_member,
+            &a.relative_path,
+        )
+            .cmp(&(
+                !b.relative_path.is_empty(),
+                !b.workspace_member,
+                &b.relative_path,
+            ))
+    });
+
+    info!(
+        "Astro Editor [PROJECT_DETECT] Found {} Astro sites in {root_path}",
+        sites.len()
+    );
+    Ok(sites)
+}
+
+/// Workspace package globs from `pnpm-workspace.yaml` and `package.json`
+fn workspace_patterns(root: &Path) -> Vec<String> {
+    let mut patterns = Vec::new();
+
+    if let Ok(content) = std::fs::read_to_string(root.join("pnpm-workspace.yaml")) {
+        patterns.extend(parse_pnpm_workspace(&content));
+    }
+
+    if let Some(package) = read_package_json(root) {
+        // npm/bun use an array, yarn also allows `{ packages: [...] }`
+        let workspaces = package.get("workspaces");
+        let list = workspaces
+            .and_then(Value::as_array)
+            .or_else(|| workspaces?.get("packages")?.as_array());
+        patterns.extend(
+            list.into_iter()
+                .flatten()
+                .filter_map(|pattern| pattern.as_str().map(str::to_string)),
+        );
+    }
+
+    debug!("Astro Editor [PROJECT_DETECT] Workspace patterns: {patterns:?}");
+    patterns
+}
+
+/// Reads the `packages:` list from `pnpm-workspace.yaml`
+fn parse_pnpm_workspace(content: &str) -> Vec<String> {
+    let mut patterns = Vec::new();
+    let mut in_packages = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if !line.starts_with([' ', '\t', '-']) {
+            in_packages = trimmed == "packages:";
+            continue;
+        }
+
+        if let Some(item) = trimmed.strip_prefix('-').filter(|_| in_packages) {
+            let item = item.split(" #").next().unwrap_or(item).trim();
+            patterns.push(item.trim_matches(['"', '\'']).to_string());
+        }
+    }
+
+    patterns
+}
+
+fn read_package_json(dir: &Path) -> Option<Value> {
+    let content = std::fs::read_to_string(dir.join("package.json")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn package_name(dir: &Path) -> Option<String> {
+    read_package_json(dir)?
+        .get("name")?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Converts a workspace glob (`apps/*`, `packages/**`) to a regex over `/`-separated
+/// relative paths. A trailing `/**` also matches the folder itself.
+fn glob_regex(pattern: &str) -> Option<Regex> {
+    let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+    let mut regex = String::from("^");
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '/' if chars.clone().collect::<String>() == "**" => {
+                regex.push_str("(?:/.*)?");
+                break;
+            }
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            _ => regex.push_str(&regex::escape(&ch.to_string())),
+        }
+    }
+    regex.push('$');
+
+    Regex::new(&regex).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_site(dir: &Path, name: Option<&str>) {
+        fs::create_dir_all(dir).unwrap();
+        fs::write(dir.join("astro.config.mjs"), "export default {}").unwrap();
+        if let Some(name) = name {
+            fs::write(
+                dir.join("package.json"),
+                format!(r#"{{ "name": "{name}" }}"#),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_parse_pnpm_workspace() {
+        let patterns = parse_pnpm_workspace(
+            "packages:\n  - 'apps/*'\n  - \"packages/**\" # shared\n  - '!**/test/**'\ncatalog:\n  - astro\n",
+        );
+        assert_eq!(patterns, vec!["apps/*", "packages/**", "!**/test/**"]);
+    }
+
+    #[test]
+    fn test_glob_regex() {
+        let apps = glob_regex("apps/*").unwrap();
+        assert!(apps.is_match("apps/www"));
+        assert!(!apps.is_match("apps/www/nested"));
+
+        let packages = glob_regex("./packages/**").unwrap();
+        assert!(packages.is_match("packages/sites/docs"));
+        assert!(!packages.is_match("apps/www"));
+
+        let tests = glob_regex("**/test/**").unwrap();
+        assert!(tests.is_match("packages/test"));
+        assert!(tests.is_match("test/site"));
+        assert!(!tests.is_match("packages/testing"));
+    }
+
+    #[tokio::test]
+    async fn test_detect_astro_sites_in_monorepo() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "name": "monorepo", "workspaces": ["apps/*", "!apps/legacy"] }"#,
+        )
+        .unwrap();
+        create_site(&root.join("apps/www"), Some("@acme/www"));
+        create_site(&root.join("apps/legacy"), None);
+        create_site(&root.join("examples/blog"), None);
+        // Dependencies are never searched
+        create_site(&root.join("node_modules/some-theme"), None);
+
+        let sites = detect_astro_sites(root.to_string_lossy().to_string())
+            .await
+            .unwrap();
+
+        let found: Vec<(&str, &str, bool)> = sites
+            .iter()
+            .map(|site| {
+                (
+                    site.relative_path.as_str(),
+                    site.name.as_str(),
+                    site.workspace_member,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("apps/www", "@acme/www", true),
+                ("apps/legacy", "legacy", false),
+                ("examples/blog", "blog", false)
+            ]
+        );
+    }
+}
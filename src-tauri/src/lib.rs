@@ -1,6 +1,10 @@
+mod astro_config;
 mod commands;
 mod models;
+mod network_fs;
 mod parser;
+mod paths;
+mod project_ignore;
 
 use commands::*;
 use std::collections::HashMap;
@@ -39,8 +43,18 @@ fn greet(name: &str) -> String {
 #[tauri::command]
 async fn update_format_menu_state(
     app_handle: tauri::AppHandle,
+    window: tauri::Window,
     enabled: bool,
 ) -> Result<(), String> {
+    // The menu bar is shared by all windows, so only the focused window's state is shown
+    if set_window_format_menu_enabled(&app_handle, window.label(), enabled) {
+        apply_format_menu_state(&app_handle, enabled);
+    }
+
+    Ok(())
+}
+
+fn apply_format_menu_state(app_handle: &tauri::AppHandle, enabled: bool) {
     // Try to enable/disable menu items using stored references
     if let Some(menu_state) = app_handle.try_state::<Mutex<MenuState>>() {
         if let Ok(state) = menu_state.lock() {
@@ -49,8 +63,13 @@ async fn update_format_menu_state(
             }
         }
     }
+}
 
-    Ok(())
+/// Sends a menu action to the focused window only
+fn emit_menu_event(app_handle: &tauri::AppHandle, event: &str) {
+    if let Some(label) = focused_window_label(app_handle) {
+        let _ = app_handle.emit_to(label.as_str(), event, ());
+    }
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -71,6 +90,7 @@ pub fn run() {
             ])
             .build())
         .manage(commands::watcher::init_watcher_state())
+        .manage(commands::windows::init_window_state())
         .setup(|app| {
             // Log app startup information
             let package_info = app.package_info();
@@ -100,6 +120,13 @@ pub fn run() {
                         true,
                         Some("CmdOrCtrl+Shift+O"),
                     )?,
+                    &MenuItem::with_id(
+                        app,
+                        "open_project_new_window",
+                        "Open Project in New Window...",
+                        true,
+                        None::<&str>,
+                    )?,
                     &MenuItem::with_id(app, "new_file", "New File", true, Some("CmdOrCtrl+N"))?,
                     &PredefinedMenuItem::separator(app)?,
                     &MenuItem::with_id(app, "save", "Save", true, Some("CmdOrCtrl+S"))?,
@@ -253,22 +280,27 @@ pub fn run() {
             // Handle menu events
             app.on_menu_event(move |app, event| match event.id().as_ref() {
                 "open_project" => {
-                    let _ = app.emit("menu-open-project", ());
+                    emit_menu_event(app, "menu-open-project");
+                }
+                "open_project_new_window" => {
+                    emit_menu_event(app, "menu-open-project-new-window");
                 }
                 "new_file" => {
-                    let _ = app.emit("menu-new-file", ());
+                    emit_menu_event(app, "menu-new-file");
                 }
                 "save" => {
-                    let _ = app.emit("menu-save", ());
+                    emit_menu_event(app, "menu-save");
                 }
                 "toggle_sidebar" => {
-                    let _ = app.emit("menu-toggle-sidebar", ());
+                    emit_menu_event(app, "menu-toggle-sidebar");
                 }
                 "toggle_frontmatter" => {
-                    let _ = app.emit("menu-toggle-frontmatter", ());
+                    emit_menu_event(app, "menu-toggle-frontmatter");
                 }
                 "enter_fullscreen" => {
-                    if let Some(window) = app.get_webview_window("main") {
+                    if let Some(window) =
+                        focused_window_label(app).and_then(|label| app.get_webview_window(&label))
+                    {
                         let _ = window.set_fullscreen(true);
                     }
                 }
@@ -290,41 +322,56 @@ pub fn run() {
                     });
                 }
                 "preferences" => {
-                    let _ = app.emit("menu-preferences", ());
+                    emit_menu_event(app, "menu-preferences");
                 }
                 "quit" => {
                     app.exit(0);
                 }
                 // Text formatting menu items
                 "format_bold" => {
-                    let _ = app.emit("menu-format-bold", ());
+                    emit_menu_event(app, "menu-format-bold");
                 }
                 "format_italic" => {
-                    let _ = app.emit("menu-format-italic", ());
+                    emit_menu_event(app, "menu-format-italic");
                 }
                 "format_link" => {
-                    let _ = app.emit("menu-format-link", ());
+                    emit_menu_event(app, "menu-format-link");
                 }
                 "format_h1" => {
-                    let _ = app.emit("menu-format-h1", ());
+                    emit_menu_event(app, "menu-format-h1");
                 }
                 "format_h2" => {
-                    let _ = app.emit("menu-format-h2", ());
+                    emit_menu_event(app, "menu-format-h2");
                 }
                 "format_h3" => {
-                    let _ = app.emit("menu-format-h3", ());
+                    emit_menu_event(app, "menu-format-h3");
                 }
                 "format_h4" => {
-                    let _ = app.emit("menu-format-h4", ());
+                    emit_menu_event(app, "menu-format-h4");
                 }
                 "format_paragraph" => {
-                    let _ = app.emit("menu-format-paragraph", ());
+                    emit_menu_event(app, "menu-format-paragraph");
                 }
                 _ => {}
             });
 
             Ok(())
         })
+        .on_window_event(|window, event| match event {
+            tauri::WindowEvent::Focused(true) => {
+                let app_handle = window.app_handle();
+                record_window_focus(app_handle, window.label());
+                let enabled = window_format_menu_enabled(app_handle, window.label());
+                apply_format_menu_state(app_handle, enabled);
+            }
+            tauri::WindowEvent::Destroyed => {
+                let app_handle = window.app_handle();
+                if let Some(project_path) = remove_window(app_handle, window.label()) {
+                    release_project_watcher(app_handle, &project_path);
+                }
+            }
+            _ => {}
+        })
         .invoke_handler(tauri::generate_handler![
             greet,
             select_project_folder,
@@ -343,6 +390,8 @@ pub fn run() {
             start_watching_project_with_content_dir,
             stop_watching_project,
             copy_text_to_clipboard,
+            paste_image_from_clipboard,
+            copy_as_html,
             update_format_menu_state,
             copy_file_to_assets,
             copy_file_to_assets_with_override,
@@ -352,6 +401,12 @@ pub fn run() {
             get_app_data_dir,
             write_app_data_file,
             read_app_data_file,
+            get_effective_project_settings,
+            get_translation_status,
+            get_image_metadata,
+            audit_images,
+            find_orphaned_assets,
+            delete_orphaned_assets,
             read_file_content,
             write_file_content,
             create_directory,
@@ -359,7 +414,20 @@ pub fn run() {
             get_app_version,
             get_platform_info,
             get_app_info,
-            get_available_ides
+            get_available_ides,
+            check_schema_migrations,
+            acknowledge_schema_changes,
+            apply_schema_migrations,
+            get_taxonomy,
+            rename_taxonomy_value,
+            get_astro_project_config,
+            detect_astro_sites,
+            get_file_stats,
+            get_collection_stats,
+            get_backlinks,
+            get_broken_links,
+            get_window_project,
+            open_project_in_new_window
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
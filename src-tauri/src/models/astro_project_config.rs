@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Project settings read from `astro.config.*`
+///
+/// Directories are relative to the project root, without a leading `./`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AstroProjectConfig {
+    pub config_file: Option<PathBuf>, // None when the project has no Astro config
+    pub src_dir: String,
+    pub public_dir: String,
+    pub base: Option<String>,
+    pub site: Option<String>,
+    pub trailing_slash: Option<String>, // "always", "never" or "ignore"
+    pub i18n: Option<I18nConfig>,
+    pub integrations: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct I18nConfig {
+    pub default_locale: Option<String>,
+    pub locales: Vec<String>,
+}
+
+impl Default for AstroProjectConfig {
+    fn default() -> Self {
+        Self {
+            config_file: None,
+            src_dir: "src".to_string(),
+            public_dir: "public".to_string(),
+            base: None,
+            site: None,
+            trailing_slash: None,
+            i18n: None,
+            integrations: Vec::new(),
+        }
+    }
+}
+
+impl AstroProjectConfig {
+    pub fn content_directory(&self) -> String {
+        self.src_path("content")
+    }
+
+    pub fn assets_directory(&self) -> String {
+        self.src_path("assets")
+    }
+
+    pub fn mdx_directory(&self) -> String {
+        self.src_path("components/mdx")
+    }
+
+    pub fn src_dir_path(&self, project_path: &Path) -> PathBuf {
+        if self.src_dir.is_empty() {
+            project_path.to_path_buf()
+        } else {
+            project_path.join(&self.src_dir)
+        }
+    }
+
+    fn src_path(&self, relative: &str) -> String {
+        if self.src_dir.is_empty() {
+            relative.to_string()
+        } else {
+            format!("{}/{relative}", self.src_dir)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directories_follow_src_dir() {
+        let default = AstroProjectConfig::default();
+        assert_eq!(default.content_directory(), "src/content");
+        assert_eq!(default.assets_directory(), "src/assets");
+
+        let custom = AstroProjectConfig {
+            src_dir: "app".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(custom.content_directory(), "app/content");
+        assert_eq!(custom.mdx_directory(), "app/components/mdx");
+
+        let root = AstroProjectConfig {
+            src_dir: String::new(),
+            ..Default::default()
+        };
+        assert_eq!(root.content_directory(), "content");
+        assert_eq!(root.src_dir_path(Path::new("/p")), PathBuf::from("/p"));
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Prose statistics for a single Markdown/MDX file
+///
+/// Counts only cover the body text: frontmatter, MDX imports and fenced code blocks
+/// are excluded.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContentStats {
+    pub word_count: usize,
+    pub character_count: usize, // Non-whitespace characters
+    pub reading_time_minutes: u32,
+    pub heading_count: usize,
+    pub image_count: usize,
+    pub link_count: usize,
+}
@@ -14,6 +14,11 @@ pub struct FileEntry {
     pub collection: String,
     pub last_modified: Option<u64>,
     pub frontmatter: Option<HashMap<String, Value>>, // Basic frontmatter for display
+    // Translation info, only set in collections that have locales
+    pub locale: Option<String>,
+    pub translation_key: Option<String>, // Shared by all translations of an entry
+    pub missing_translations: Vec<String>,
+    pub outdated_translation: bool, // The source-locale entry changed after this one
 }
 
 impl FileEntry {
@@ -48,6 +53,10 @@ impl FileEntry {
             collection,
             last_modified,
             frontmatter: None, // Will be populated by enhanced scanning
+            locale: None,
+            translation_key: None,
+            missing_translations: Vec::new(),
+            outdated_translation: false,
         }
     }
 
@@ -62,6 +71,20 @@ impl FileEntry {
         self
     }
 
+    /// Marks the entry as the `locale` translation of `translation_key`. `relative_id` is
+    /// the path inside the collection, which keeps IDs unique across locale folders.
+    pub fn with_translation(
+        mut self,
+        locale: String,
+        translation_key: String,
+        relative_id: &str,
+    ) -> Self {
+        self.id = format!("{}/{relative_id}", self.collection);
+        self.locale = Some(locale);
+        self.translation_key = Some(translation_key);
+        self
+    }
+
     #[allow(dead_code)]
     pub fn is_markdown(&self) -> bool {
         matches!(self.extension.as_str(), "md" | "mdx")
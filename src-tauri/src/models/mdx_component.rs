@@ -6,6 +6,17 @@ pub struct PropInfo {
     pub prop_type: String, // e.g., "'warning' | 'info'", "string", "boolean"
     pub is_optional: bool,
     pub default_value: Option<String>, // For optional props with defaults
+    pub description: Option<String>,   // From the prop's JSDoc comment
+}
+
+/// The UI framework a component is written for
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentFramework {
+    Astro,
+    React, // Also Preact and Solid, which share the `.tsx`/`.jsx` extensions
+    Vue,
+    Svelte,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -15,4 +26,6 @@ pub struct MdxComponent {
     pub props: Vec<PropInfo>,
     pub has_slot: bool,
     pub description: Option<String>, // Extracted from JSDoc comments if available
+    pub framework: ComponentFramework,
+    pub client_directive: Option<String>, // e.g. "client:load"; None when rendered statically
 }
@@ -1,7 +1,11 @@
+pub mod astro_project_config;
 pub mod collection;
+pub mod content_stats;
 pub mod file_entry;
 pub mod mdx_component;
 
+pub use astro_project_config::{AstroProjectConfig, I18nConfig};
 pub use collection::Collection;
+pub use content_stats::ContentStats;
 pub use file_entry::FileEntry;
-pub use mdx_component::{MdxComponent, PropInfo};
+pub use mdx_component::{ComponentFramework, MdxComponent, PropInfo};
@@ -0,0 +1,115 @@
+use std::path::{Path, PathBuf};
+
+/// Filesystem types where native change notifications don't arrive (or only arrive for
+/// changes made on this machine), so the watcher has to poll instead
+const NETWORK_FILESYSTEMS: &[&str] = &[
+    "nfs",
+    "nfs4",
+    "cifs",
+    "smb3",
+    "smbfs",
+    "afpfs",
+    "webdav",
+    "9p",
+    "virtiofs",
+    "vboxsf",
+    "fuse.sshfs",
+    "fuse.grpcfuse",
+    "fakeowner",
+];
+
+/// Returns the filesystem type if `path` is on a network or VM-shared filesystem
+pub fn network_filesystem(path: &Path) -> Option<String> {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let mounts = read_mounts()?;
+
+    // The deepest mount point containing the path is the one it lives on
+    let (_, fs_type) = mounts
+        .into_iter()
+        .filter(|(mount_point, _)| path.starts_with(mount_point))
+        .max_by_key(|(mount_point, _)| mount_point.components().count())?;
+
+    NETWORK_FILESYSTEMS
+        .contains(&fs_type.as_str())
+        .then_some(fs_type)
+}
+
+#[cfg(target_os = "linux")]
+fn read_mounts() -> Option<Vec<(PathBuf, String)>> {
+    let content = std::fs::read_to_string("/proc/self/mounts").ok()?;
+    Some(parse_proc_mounts(&content))
+}
+
+#[cfg(target_os = "macos")]
+fn read_mounts() -> Option<Vec<(PathBuf, String)>> {
+    let output = std::process::Command::new("/sbin/mount").output().ok()?;
+    Some(parse_mount_output(&String::from_utf8_lossy(&output.stdout)))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn read_mounts() -> Option<Vec<(PathBuf, String)>> {
+    None
+}
+
+/// Parses `/proc/self/mounts` lines: `device mount_point fs_type options dump pass`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_proc_mounts(content: &str) -> Vec<(PathBuf, String)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let _device = fields.next()?;
+            // Spaces in mount points are escaped as \040
+            let mount_point = fields.next()?.replace("\\040", " ");
+            let fs_type = fields.next()?;
+            Some((PathBuf::from(mount_point), fs_type.to_string()))
+        })
+        .collect()
+}
+
+/// Parses macOS `mount` output lines: `device on /mount/point (fs_type, options...)`
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_mount_output(content: &str) -> Vec<(PathBuf, String)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (_, rest) = line.split_once(" on ")?;
+            let (mount_point, details) = rest.rsplit_once(" (")?;
+            let fs_type = details.split([',', ')']).next()?.trim();
+            Some((PathBuf::from(mount_point), fs_type.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proc_mounts() {
+        let mounts = parse_proc_mounts(
+            "/dev/sda1 / ext4 rw,relatime 0 0\n\
+             server:/export /mnt/shared\\040work nfs4 rw,vers=4.2 0 0\n",
+        );
+
+        assert_eq!(mounts.len(), 2);
+        assert_eq!(
+            mounts[1],
+            (PathBuf::from("/mnt/shared work"), "nfs4".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_mount_output() {
+        let mounts = parse_mount_output(
+            "/dev/disk3s1s1 on / (apfs, sealed, local, read-only, journaled)\n\
+             //user@server/Projects on /Volumes/Projects (smbfs, nodev, nosuid, mounted by user)\n",
+        );
+
+        assert_eq!(mounts.len(), 2);
+        assert_eq!(
+            mounts[1],
+            (PathBuf::from("/Volumes/Projects"), "smbfs".to_string())
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::astro_config::load_astro_project_config;
 use crate::models::Collection;
 use regex::Regex;
 use std::path::Path;
@@ -62,10 +63,13 @@ pub struct ParsedSchema {
 
 /// Parse Astro content config file and extract collection definitions
 pub fn parse_astro_config(project_path: &Path) -> Result<Vec<Collection>, String> {
-    // Try both possible config file locations
+    // Try both possible config file locations, inside the configured srcDir
+    let astro_config = load_astro_project_config(project_path);
+    let src_dir = astro_config.src_dir_path(project_path);
+    let content_dir = project_path.join(astro_config.content_directory());
     let config_paths = [
-        project_path.join("src").join("content.config.ts"), // New format
-        project_path.join("src").join("content").join("config.ts"), // Old format
+        src_dir.join("content.config.ts"), // New format
+        content_dir.join("config.ts"),     // Old format
     ];
 
     for config_path in &config_paths {
@@ -73,7 +77,7 @@ pub fn parse_astro_config(project_path: &Path) -> Result<Vec<Collection>, String
             let content = std::fs::read_to_string(config_path)
                 .map_err(|e| format!("Failed to read config file: {e}"))?;
 
-            return parse_collections_from_content(&content, project_path);
+            return parse_collections_from_content(&content, &content_dir);
         }
     }
 
@@ -82,10 +86,9 @@ pub fn parse_astro_config(project_path: &Path) -> Result<Vec<Collection>, String
 
 fn parse_collections_from_content(
     content: &str,
-    project_path: &Path,
+    content_dir: &Path,
 ) -> Result<Vec<Collection>, String> {
     let mut collections = Vec::new();
-    let content_dir = project_path.join("src").join("content");
 
     // Remove comments and normalize whitespace
     let clean_content = remove_comments(content);
@@ -94,7 +97,7 @@ fn parse_collections_from_content(
     if let Some(collections_block) = extract_collections_block(&clean_content) {
         collections.extend(parse_collection_definitions(
             &collections_block,
-            &content_dir,
+            content_dir,
             &clean_content, // Pass full content for schema extraction
         )?);
     }
@@ -102,7 +105,7 @@ fn parse_collections_from_content(
     Ok(collections)
 }
 
-fn remove_comments(content: &str) -> String {
+pub(crate) fn remove_comments(content: &str) -> String {
     // Improved comment removal that handles edge cases better
     let mut result = String::new();
     let mut chars = content.chars().peekable();
@@ -1002,7 +1005,8 @@ export default defineConfig({
 
         fs::create_dir_all(&blog_path).unwrap();
 
-        let result = parse_collections_from_content(content, &project_path);
+        let result =
+            parse_collections_from_content(content, &project_path.join("src").join("content"));
         assert!(result.is_ok());
 
         let collections = result.unwrap();
@@ -1022,7 +1026,8 @@ export default defineConfig({
 });
 "#;
         let project_path = PathBuf::from("/tmp/empty-project");
-        let result = parse_collections_from_content(content, &project_path);
+        let result =
+            parse_collections_from_content(content, &project_path.join("src").join("content"));
         assert!(result.is_ok());
         assert_eq!(result.unwrap().len(), 0);
     }
@@ -1074,7 +1079,8 @@ export default defineConfig({
         fs::create_dir_all(&blog_path).unwrap();
         fs::create_dir_all(&docs_path).unwrap();
 
-        let result = parse_collections_from_content(content, &project_path);
+        let result =
+            parse_collections_from_content(content, &project_path.join("src").join("content"));
         assert!(result.is_ok());
 
         let collections = result.unwrap();
@@ -1129,7 +1135,8 @@ export const collections = {
 
         fs::create_dir_all(&test_path).unwrap();
 
-        let result = parse_collections_from_content(content, &project_path);
+        let result =
+            parse_collections_from_content(content, &project_path.join("src").join("content"));
         assert!(result.is_ok());
 
         let collections = result.unwrap();
@@ -1165,7 +1172,8 @@ export const collections = {
 
         fs::create_dir_all(&test_path).unwrap();
 
-        let result = parse_collections_from_content(content, &project_path);
+        let result =
+            parse_collections_from_content(content, &project_path.join("src").join("content"));
         assert!(result.is_ok());
 
         let collections = result.unwrap();
@@ -1203,7 +1211,8 @@ export const collections = {
 
         fs::create_dir_all(&test_path).unwrap();
 
-        let result = parse_collections_from_content(content, &project_path);
+        let result =
+            parse_collections_from_content(content, &project_path.join("src").join("content"));
         assert!(result.is_ok());
 
         let collections = result.unwrap();
@@ -1259,7 +1268,8 @@ export const collections = {
 
         fs::create_dir_all(&test_path).unwrap();
 
-        let result = parse_collections_from_content(content, &project_path);
+        let result =
+            parse_collections_from_content(content, &project_path.join("src").join("content"));
         assert!(result.is_ok());
 
         let collections = result.unwrap();
@@ -1306,7 +1316,8 @@ export const collections = {
 
         fs::create_dir_all(&test_path).unwrap();
 
-        let result = parse_collections_from_content(content, &project_path);
+        let result =
+            parse_collections_from_content(content, &project_path.join("src").join("content"));
         assert!(result.is_ok());
 
         let collections = result.unwrap();
@@ -1388,7 +1399,8 @@ export const collections = {
 
         fs::create_dir_all(&test_path).unwrap();
 
-        let result = parse_collections_from_content(content, &project_path);
+        let result =
+            parse_collections_from_content(content, &project_path.join("src").join("content"));
         assert!(result.is_ok());
 
         let collections = result.unwrap();
@@ -0,0 +1,62 @@
+use std::path::{Component, Path, PathBuf};
+
+/// Lexically normalizes a path, resolving `.` and `..` without touching the filesystem
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    normalized
+}
+
+/// Computes the relative path from directory `from` to `to`
+pub(crate) fn relative_path(from: &Path, to: &Path) -> PathBuf {
+    let from: Vec<Component> = from.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let mut relative = PathBuf::new();
+    for _ in common..from.len() {
+        relative.push("..");
+    }
+    for component in &to[common..] {
+        relative.push(component.as_os_str());
+    }
+    relative
+}
+
+/// Formats a relative path with forward slashes, for links, IDs and settings
+pub(crate) fn to_slash_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_path() {
+        assert_eq!(
+            relative_path(Path::new("/a/b/c"), Path::new("/a/d/e.md")),
+            PathBuf::from("../../d/e.md")
+        );
+        assert_eq!(
+            relative_path(Path::new("/a/b"), Path::new("/a/b/c.md")),
+            PathBuf::from("c.md")
+        );
+        assert_eq!(
+            normalize_path(Path::new("/a/b/./../c.md")),
+            PathBuf::from("/a/c.md")
+        );
+        assert_eq!(to_slash_path(Path::new("blog/2024/post")), "blog/2024/post");
+    }
+}
@@ -0,0 +1,143 @@
+use crate::astro_config::find_astro_config;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::WalkBuilder;
+use log::warn;
+use std::path::{Path, PathBuf};
+
+/// Project-level ignore file, using the same syntax as `.gitignore`
+pub const IGNORE_FILE_NAME: &str = ".astro-editor-ignore";
+
+/// Ignore rules for a project: `.gitignore`, `.astro-editor-ignore` and patterns from settings
+pub struct IgnoreRules {
+    root: PathBuf,
+    matcher: Gitignore,
+}
+
+impl IgnoreRules {
+    /// Loads the ignore files in the project root along with any extra patterns
+    pub fn load(project_root: &Path, extra_patterns: &[String]) -> Self {
+        let mut builder = GitignoreBuilder::new(project_root);
+        for file_name in [".gitignore", IGNORE_FILE_NAME] {
+            let path = project_root.join(file_name);
+            if path.exists() {
+                if let Some(e) = builder.add(&path) {
+                    warn!(
+                        "Astro Editor [IGNORE] Failed to read {}: {e}",
+                        path.display()
+                    );
+                }
+            }
+        }
+        Self::build(project_root, builder, extra_patterns)
+    }
+
+    /// Rules from settings only, for walkers that read the ignore files themselves
+    pub fn from_patterns(project_root: &Path, patterns: &[String]) -> Self {
+        Self::build(project_root, GitignoreBuilder::new(project_root), patterns)
+    }
+
+    fn build(project_root: &Path, mut builder: GitignoreBuilder, patterns: &[String]) -> Self {
+        for pattern in patterns {
+            if let Err(e) = builder.add_line(None, pattern) {
+                warn!("Astro Editor [IGNORE] Invalid ignore pattern '{pattern}': {e}");
+            }
+        }
+
+        let matcher = builder.build().unwrap_or_else(|e| {
+            warn!("Astro Editor [IGNORE] Failed to build ignore rules: {e}");
+            Gitignore::empty()
+        });
+
+        Self {
+            root: project_root.to_path_buf(),
+            matcher,
+        }
+    }
+
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        // The matcher only handles paths inside its root
+        path.starts_with(&self.root)
+            && self
+                .matcher
+                .matched_path_or_any_parents(path, is_dir)
+                .is_ignore()
+    }
+}
+
+/// A directory walker that honours `.gitignore` (including those in parent directories),
+/// `.astro-editor-ignore` and the given extra patterns
+pub fn project_walker(dir: &Path, project_root: &Path, extra_patterns: &[String]) -> WalkBuilder {
+    let rules = IgnoreRules::from_patterns(project_root, extra_patterns);
+
+    let mut builder = WalkBuilder::new(dir);
+    builder
+        .hidden(false)
+        .parents(true)
+        .git_global(false)
+        .require_git(false)
+        .add_custom_ignore_filename(IGNORE_FILE_NAME)
+        .filter_entry(move |entry| {
+            let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+            !rules.is_ignored(entry.path(), is_dir)
+        });
+    builder
+}
+
+/// Finds the project root for a path inside a project (the nearest directory with an
+/// Astro config or `package.json`), falling back to the path itself
+pub fn find_project_root(path: &Path) -> PathBuf {
+    path.ancestors()
+        .find(|dir| dir.join("package.json").exists() || find_astro_config(dir).is_some())
+        .unwrap_or(path)
+        .to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_ignore_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(".gitignore"), "dist/\n*.generated.md\n").unwrap();
+        fs::write(root.join(IGNORE_FILE_NAME), "src/content/drafts-archive/\n").unwrap();
+
+        let rules = IgnoreRules::load(root, &["**/scratch.md".to_string()]);
+
+        assert!(rules.is_ignored(&root.join("dist/index.md"), false));
+        assert!(rules.is_ignored(&root.join("src/content/blog/api.generated.md"), false));
+        assert!(rules.is_ignored(&root.join("src/content/drafts-archive/old.md"), false));
+        assert!(rules.is_ignored(&root.join("src/content/blog/scratch.md"), false));
+        assert!(!rules.is_ignored(&root.join("src/content/blog/post.md"), false));
+        // Paths outside the project are never ignored
+        assert!(!rules.is_ignored(Path::new("/elsewhere/dist/index.md"), false));
+    }
+
+    #[test]
+    fn test_project_walker_reads_parent_ignore_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let collection = root.join("src/content/blog");
+        fs::create_dir_all(&collection).unwrap();
+        fs::write(root.join("package.json"), "{}").unwrap();
+        fs::write(root.join(".gitignore"), "*.generated.md\n").unwrap();
+        fs::write(collection.join("post.md"), "").unwrap();
+        fs::write(collection.join("api.generated.md"), "").unwrap();
+        fs::write(collection.join("notes.md"), "").unwrap();
+
+        assert_eq!(find_project_root(&collection), root);
+
+        let mut found: Vec<String> = project_walker(&collection, root, &["notes.md".to_string()])
+            .build()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.depth() > 0)
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect();
+        found.sort();
+
+        assert_eq!(found, vec!["post.md"]);
+    }
+}
@@ -255,7 +255,14 @@ export function ComponentBuilderDialog() {
                               : 'opacity-0'
                           )}
                         />
-                        <span className="flex-1">{prop.name}</span>
+                        <div className="flex flex-1 flex-col">
+                          <span>{prop.name}</span>
+                          {prop.description && (
+                            <span className="text-xs text-muted-foreground">
+                              {prop.description}
+                            </span>
+                          )}
+                        </div>
                         <span className="text-xs text-muted-foreground">
                           {prop.prop_type}
                         </span>
@@ -285,9 +292,17 @@ export function ComponentBuilderDialog() {
                               : 'opacity-0'
                           )}
                         />
-                        <span className="flex-1">{prop.name}</span>
+                        <div className="flex flex-1 flex-col">
+                          <span>{prop.name}</span>
+                          {prop.description && (
+                            <span className="text-xs text-muted-foreground">
+                              {prop.description}
+                            </span>
+                          )}
+                        </div>
                         <span className="text-xs text-muted-foreground">
                           {prop.prop_type}
+                          {prop.default_value && ` = ${prop.default_value}`}
                         </span>
                       </CommandItem>
                     ))}
@@ -7,6 +7,7 @@ import { LeftSidebar } from './LeftSidebar'
 import { MainEditor } from './MainEditor'
 import { RightSidebar } from './RightSidebar'
 import { StatusBar } from './StatusBar'
+import { SiteSelectionDialog } from './SiteSelectionDialog'
 import { FrontmatterPanel } from '../frontmatter'
 import { CommandPalette } from '../command-palette'
 import { ComponentBuilderDialog } from '../component-builder'
@@ -126,6 +127,7 @@ export const Layout: React.FC = () => {
       {/* Floating components */}
       <CommandPalette />
       <ComponentBuilderDialog />
+      <SiteSelectionDialog />
       <PreferencesDialog
         open={preferencesOpen}
         onOpenChange={setPreferencesOpen}
@@ -0,0 +1,58 @@
+import React from 'react'
+import { Folder } from 'lucide-react'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Badge } from '../ui/badge'
+import { useProjectStore } from '../../store/projectStore'
+
+/**
+ * Shown when an opened folder holds several Astro sites (e.g. a monorepo root),
+ * so the user can pick the one to edit
+ */
+export const SiteSelectionDialog: React.FC = () => {
+  const { siteCandidates, setProject, clearSiteCandidates } = useProjectStore()
+
+  return (
+    <Dialog
+      open={siteCandidates !== null}
+      onOpenChange={open => {
+        if (!open) clearSiteCandidates()
+      }}
+    >
+      <DialogContent className="font-sans sm:max-w-[520px]">
+        <DialogHeader>
+          <DialogTitle>Choose an Astro site</DialogTitle>
+          <DialogDescription>
+            This folder contains several Astro sites. Pick the one to open.
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="flex flex-col gap-1">
+          {siteCandidates?.map(site => (
+            <button
+              key={site.path}
+              onClick={() => setProject(site.path)}
+              className="hover:bg-accent flex items-center gap-3 rounded-md px-3 py-2 text-left"
+            >
+              <Folder className="text-muted-foreground size-4 shrink-0" />
+              <div className="min-w-0 flex-1">
+                <div className="truncate text-sm font-medium">{site.name}</div>
+                <div className="text-muted-foreground truncate text-xs">
+                  {site.relative_path}
+                </div>
+              </div>
+              {site.workspace_member && (
+                <Badge variant="secondary">Workspace</Badge>
+              )}
+            </button>
+          ))}
+        </div>
+      </DialogContent>
+    </Dialog>
+  )
+}
@@ -4,3 +4,4 @@ export { StatusBar } from './StatusBar'
 export { MainEditor } from './MainEditor'
 export { LeftSidebar } from './LeftSidebar'
 export { RightSidebar } from './RightSidebar'
+export { SiteSelectionDialog } from './SiteSelectionDialog'
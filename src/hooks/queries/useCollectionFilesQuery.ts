@@ -4,6 +4,7 @@ import { useQuery } from '@tanstack/react-query'
 import { invoke } from '@tauri-apps/api/core'
 import { queryKeys } from '@/lib/query-keys'
 import { FileEntry } from '@/store' // Import type from store for now
+import { useProjectStore } from '@/store/projectStore'
 
 const fetchCollectionFiles = async (
   collectionPath: string
@@ -13,6 +14,8 @@ const fetchCollectionFiles = async (
   }
   return invoke('scan_collection_files', {
     collectionPath,
+    ignorePatterns:
+      useProjectStore.getState().currentProjectSettings?.ignorePatterns,
   })
 }
 
@@ -1,12 +1,15 @@
 import { useQuery } from '@tanstack/react-query'
 import { invoke } from '@tauri-apps/api/core'
 import { queryKeys } from '../../lib/query-keys'
+import { useProjectStore } from '../../store/projectStore'
+import { ASTRO_PATHS } from '../../lib/constants'
 
 interface PropInfo {
   name: string
   prop_type: string
   is_optional: boolean
   default_value?: string | null
+  description?: string | null
 }
 
 export interface MdxComponent {
@@ -15,14 +18,20 @@ export interface MdxComponent {
   props: PropInfo[]
   has_slot: boolean
   description?: string | null
+  framework: 'astro' | 'react' | 'vue' | 'svelte'
+  client_directive?: string | null // e.g. 'client:load'
 }
 
 export function useMdxComponentsQuery(
   projectPath: string | null,
   mdxDirectory?: string
 ) {
+  // The default location follows srcDir from astro.config, so leave it to the backend
+  const directory =
+    mdxDirectory !== ASTRO_PATHS.MDX_COMPONENTS_DIR ? mdxDirectory : undefined
+
   return useQuery({
-    queryKey: queryKeys.mdxComponents(projectPath || '', mdxDirectory),
+    queryKey: queryKeys.mdxComponents(projectPath || '', directory),
     queryFn: async () => {
       if (!projectPath) {
         return []
@@ -30,7 +39,9 @@ export function useMdxComponentsQuery(
 
       const components = await invoke<MdxComponent[]>('scan_mdx_components', {
         projectPath,
-        mdxDirectory,
+        mdxDirectory: directory,
+        ignorePatterns:
+          useProjectStore.getState().currentProjectSettings?.ignorePatterns,
       })
       return components
     },
@@ -1,12 +1,12 @@
 import { useEffect, useState, useCallback } from 'react'
 import { useHotkeys } from 'react-hotkeys-hook'
-import { listen } from '@tauri-apps/api/event'
 import { invoke } from '@tauri-apps/api/core'
 import { useEditorStore } from '../store/editorStore'
 import { useProjectStore } from '../store/projectStore'
 import { useUIStore } from '../store/uiStore'
 import { globalCommandRegistry } from '../lib/editor/commands'
 import { toast } from '../lib/toast'
+import { listenToWindow } from '../lib/window-events'
 import { initializeRustToastBridge } from '../lib/rust-toast-bridge'
 import { focusEditor } from '../lib/focus-utils'
 import { useCreateFile } from './useCreateFile'
@@ -377,83 +377,100 @@ export function useLayoutEventListeners() {
       }
     }
 
+    const handleOpenProjectInNewWindow = async () => {
+      try {
+        const projectPath = await invoke<string>('select_project_folder')
+        if (projectPath) {
+          await invoke('open_project_in_new_window', { projectPath })
+        }
+      } catch (error) {
+        toast.error('Failed to open project', {
+          description:
+            error instanceof Error ? error.message : 'Unknown error occurred',
+        })
+      }
+    }
+
     // Store all unlisten functions for cleanup
     const unlistenFunctions: Array<() => void> = []
 
     // Set up all listeners asynchronously
     const setupListeners = async () => {
       const unlisteners = await Promise.all([
-        listen('menu-open-project', () => {
+        listenToWindow('menu-open-project', () => {
           void handleOpenProject()
         }),
-        listen('menu-save', () => {
+        listenToWindow('menu-open-project-new-window', () => {
+          void handleOpenProjectInNewWindow()
+        }),
+        listenToWindow('menu-save', () => {
           const { currentFile, isDirty, saveFile } = useEditorStore.getState()
           if (currentFile && isDirty) {
             void saveFile()
           }
         }),
-        listen('menu-toggle-sidebar', () => {
+        listenToWindow('menu-toggle-sidebar', () => {
           useUIStore.getState().toggleSidebar()
         }),
-        listen('menu-toggle-frontmatter', () => {
+        listenToWindow('menu-toggle-frontmatter', () => {
           useUIStore.getState().toggleFrontmatterPanel()
         }),
-        listen('menu-new-file', () => {
+        listenToWindow('menu-new-file', () => {
           const { selectedCollection } = useProjectStore.getState()
           if (selectedCollection) {
             void createNewFileWithQuery()
           }
         }),
         // Text formatting menu listeners
-        listen('menu-format-bold', () => {
+        listenToWindow('menu-format-bold', () => {
           const { currentFile } = useEditorStore.getState()
           if (currentFile) {
             globalCommandRegistry.execute('toggleBold')
           }
         }),
-        listen('menu-format-italic', () => {
+        listenToWindow('menu-format-italic', () => {
           const { currentFile } = useEditorStore.getState()
           if (currentFile) {
             globalCommandRegistry.execute('toggleItalic')
           }
         }),
-        listen('menu-format-link', () => {
+        listenToWindow('menu-format-link', () => {
           const { currentFile } = useEditorStore.getState()
           if (currentFile) {
             globalCommandRegistry.execute('createLink')
           }
         }),
-        listen('menu-format-h1', () => {
+        listenToWindow('menu-format-h1', () => {
           const { currentFile } = useEditorStore.getState()
           if (currentFile) {
             globalCommandRegistry.execute('formatHeading', 1)
           }
         }),
-        listen('menu-format-h2', () => {
+        listenToWindow('menu-format-h2', () => {
           const { currentFile } = useEditorStore.getState()
           if (currentFile) {
             globalCommandRegistry.execute('formatHeading', 2)
           }
         }),
-        listen('menu-format-h3', () => {
+        listenToWindow('menu-format-h3', () => {
           const { currentFile } = useEditorStore.getState()
           if (currentFile) {
             globalCommandRegistry.execute('formatHeading', 3)
           }
         }),
-        listen('menu-format-h4', () => {
+        listenToWindow('menu-format-h4', () => {
           const { currentFile } = useEditorStore.getState()
           if (currentFile) {
             globalCommandRegistry.execute('formatHeading', 4)
           }
         }),
-        listen('menu-format-paragraph', () => {
+        listenToWindow('menu-format-paragraph', () => {
           const { currentFile } = useEditorStore.getState()
           if (currentFile) {
             globalCommandRegistry.execute('formatHeading', 0)
           }
         }),
-        listen('menu-preferences', () => {
+        listenToWindow('menu-preferences', () => {
           handleSetPreferencesOpen(true)
         }),
       ])
@@ -15,6 +15,7 @@ import {
   Eye,
   Edit,
   Highlighter,
+  Copy,
 } from 'lucide-react'
 import { AppCommand, CommandContext } from './types'
 import { Collection, FileEntry } from '../../store'
@@ -22,6 +23,36 @@ import { toast } from '../toast'
 import { queryClient } from '../query-client'
 import { queryKeys } from '../query-keys'
 import { useEditorStore } from '../../store/editorStore'
+import { globalCommandRegistry } from '../editor/commands'
+
+/**
+ * Copy the editor selection (or the whole document) to the clipboard as HTML
+ * @param includeFrontmatter - Copy the whole document, frontmatter included
+ */
+const copyAsHtml = async (includeFrontmatter: boolean) => {
+  const { editorContent, rawFrontmatter } = useEditorStore.getState()
+  const view = globalCommandRegistry.getEditorView()
+  const selection = view?.state.selection.main
+  const selectedText =
+    !includeFrontmatter && selection && !selection.empty
+      ? view!.state.sliceDoc(selection.from, selection.to)
+      : ''
+
+  const markdown =
+    selectedText ||
+    (includeFrontmatter && rawFrontmatter
+      ? `---\n${rawFrontmatter}\n---\n\n${editorContent}`
+      : editorContent)
+
+  try {
+    await invoke('copy_as_html', { markdown, includeFrontmatter })
+    toast.success(selectedText ? 'Selection copied as HTML' : 'Copied as HTML')
+  } catch (error) {
+    toast.error('Failed to copy as HTML', {
+      description: error instanceof Error ? error.message : String(error),
+    })
+  }
+}
 
 /**
  * File-related commands
@@ -53,6 +84,32 @@ export const fileCommands: AppCommand[] = [
       return Boolean(context.currentFile && context.isDirty)
     },
   },
+  {
+    id: 'copy-as-html',
+    label: 'Copy as HTML',
+    description: 'Copy the selection or document as formatted text',
+    icon: Copy,
+    group: 'file',
+    execute: async () => {
+      await copyAsHtml(false)
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile)
+    },
+  },
+  {
+    id: 'copy-as-html-with-frontmatter',
+    label: 'Copy as HTML with Frontmatter',
+    description: 'Copy the document and its frontmatter as formatted text',
+    icon: Copy,
+    group: 'file',
+    execute: async () => {
+      await copyAsHtml(true)
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile)
+    },
+  },
   {
     id: 'close-file',
     label: 'Close File',
@@ -160,7 +160,7 @@ describe('File Processing', () => {
   describe('processDroppedFile', () => {
     it('should process image file successfully', async () => {
       const mockNewPath = 'assets/collection/image.png'
-      mockInvoke.mockResolvedValue(mockNewPath)
+      mockInvoke.mockResolvedValue({ path: mockNewPath, deduplicated: false })
 
       const result = await processDroppedFile(
         '/path/to/image.png',
@@ -173,6 +173,7 @@ describe('File Processing', () => {
         filename: 'image.png',
         isImage: true,
         markdownText: '![image.png](/assets/collection/image.png)',
+        deduplicated: false,
       })
 
       expect(mockInvoke).toHaveBeenCalledWith('copy_file_to_assets', {
@@ -182,9 +183,40 @@ describe('File Processing', () => {
       })
     })
 
+    it('should use a path relative to the target document', async () => {
+      mockInvoke.mockResolvedValue({
+        path: '../../assets/blog/image.png',
+        deduplicated: true,
+      })
+
+      const result = await processDroppedFile(
+        '/path/to/image.png',
+        '/project/path',
+        'blog',
+        '/project/path/src/content/blog/post.md'
+      )
+
+      expect(result.markdownText).toBe(
+        '![image.png](../../assets/blog/image.png)'
+      )
+      expect(mockInvoke).toHaveBeenCalledWith(
+        'copy_file_to_assets_with_override',
+        {
+          sourcePath: '/path/to/image.png',
+          projectPath: '/project/path',
+          collection: 'blog',
+          assetsDirectory: undefined,
+          targetDocument: '/project/path/src/content/blog/post.md',
+          pathStyle: 'relative',
+          naming: { folderTemplate: undefined, filenameTemplate: undefined },
+          processing: undefined,
+        }
+      )
+    })
+
     it('should process non-image file successfully', async () => {
       const mockNewPath = 'assets/collection/document.pdf'
-      mockInvoke.mockResolvedValue(mockNewPath)
+      mockInvoke.mockResolvedValue({ path: mockNewPath, deduplicated: false })
 
       const result = await processDroppedFile(
         '/path/to/document.pdf',
@@ -197,6 +229,7 @@ describe('File Processing', () => {
         filename: 'document.pdf',
         isImage: false,
         markdownText: '[document.pdf](/assets/collection/document.pdf)',
+        deduplicated: false,
       })
     })
 
@@ -219,7 +252,7 @@ describe('File Processing', () => {
 
     it('should handle Windows paths', async () => {
       const mockNewPath = 'assets/collection/file.txt'
-      mockInvoke.mockResolvedValue(mockNewPath)
+      mockInvoke.mockResolvedValue({ path: mockNewPath, deduplicated: false })
 
       const result = await processDroppedFile(
         'C:\\Users\\User\\file.txt',
@@ -232,12 +265,13 @@ describe('File Processing', () => {
         filename: 'file.txt',
         isImage: false,
         markdownText: '[file.txt](/assets/collection/file.txt)',
+        deduplicated: false,
       })
     })
 
     it('should handle complex filenames', async () => {
       const mockNewPath = 'assets/collection/my-file.final.v2.png'
-      mockInvoke.mockResolvedValue(mockNewPath)
+      mockInvoke.mockResolvedValue({ path: mockNewPath, deduplicated: false })
 
       const result = await processDroppedFile(
         '/path/to/my-file.final.v2.png',
@@ -251,6 +285,7 @@ describe('File Processing', () => {
         isImage: true,
         markdownText:
           '![my-file.final.v2.png](/assets/collection/my-file.final.v2.png)',
+        deduplicated: false,
       })
     })
   })
@@ -258,8 +293,14 @@ describe('File Processing', () => {
   describe('processDroppedFiles', () => {
     it('should process multiple files successfully', async () => {
       mockInvoke
-        .mockResolvedValueOnce('assets/collection/image.png')
-        .mockResolvedValueOnce('assets/collection/document.pdf')
+        .mockResolvedValueOnce({
+          path: 'assets/collection/image.png',
+          deduplicated: false,
+        })
+        .mockResolvedValueOnce({
+          path: 'assets/collection/document.pdf',
+          deduplicated: false,
+        })
 
       const result = await processDroppedFiles(
         ['/path/to/image.png', '/path/to/document.pdf'],
@@ -273,12 +314,14 @@ describe('File Processing', () => {
         filename: 'image.png',
         isImage: true,
         markdownText: '![image.png](/assets/collection/image.png)',
+        deduplicated: false,
       })
       expect(result[1]).toEqual({
         originalPath: '/path/to/document.pdf',
         filename: 'document.pdf',
         isImage: false,
         markdownText: '[document.pdf](/assets/collection/document.pdf)',
+        deduplicated: false,
       })
     })
 
@@ -293,7 +336,10 @@ describe('File Processing', () => {
 
     it('should handle mix of successes and failures', async () => {
       mockInvoke
-        .mockResolvedValueOnce('assets/collection/image.png')
+        .mockResolvedValueOnce({
+          path: 'assets/collection/image.png',
+          deduplicated: false,
+        })
         .mockRejectedValueOnce(new Error('Copy failed'))
 
       const result = await processDroppedFiles(
@@ -308,6 +354,7 @@ describe('File Processing', () => {
         filename: 'image.png',
         isImage: true,
         markdownText: '![image.png](/assets/collection/image.png)',
+        deduplicated: false,
       })
       expect(result[1]).toEqual({
         originalPath: '/path/to/document.pdf',
@@ -325,7 +372,10 @@ describe('File Processing', () => {
       mockInvoke.mockImplementation((_, args) => {
         const { sourcePath } = args as { sourcePath: string }
         const filename = sourcePath.split('/').pop()
-        return Promise.resolve(`assets/collection/${filename}`)
+        return Promise.resolve({
+          path: `assets/collection/${filename}`,
+          deduplicated: false,
+        })
       })
 
       const result = await processDroppedFiles(
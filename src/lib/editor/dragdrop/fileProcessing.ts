@@ -1,5 +1,5 @@
 import { invoke } from '@tauri-apps/api/core'
-import { ProcessedFile } from './types'
+import { ImportedAsset, ProcessedFile } from './types'
 import { useProjectStore } from '../../../store/projectStore'
 import { ASTRO_PATHS } from '../../constants'
 
@@ -66,53 +66,135 @@ export const formatAsMarkdown = (
   }
 }
 
+/**
+ * Asset settings for importing into a collection, from the project settings
+ * @param collection - Name of the collection
+ * @returns Options for the asset import commands
+ */
+export const getAssetImportOptions = (collection: string) => {
+  const { currentProjectSettings } = useProjectStore.getState()
+  const configuredAssetsDirectory =
+    currentProjectSettings?.pathOverrides?.assetsDirectory
+  const assets = currentProjectSettings?.assets
+  const collectionNaming = assets?.collections?.[collection]
+
+  return {
+    assetsDirectory:
+      configuredAssetsDirectory !== ASTRO_PATHS.ASSETS_DIR
+        ? configuredAssetsDirectory
+        : undefined,
+    pathStyle: assets?.pathStyle ?? 'relative',
+    naming: {
+      folderTemplate:
+        collectionNaming?.folderTemplate ?? assets?.folderTemplate,
+      filenameTemplate:
+        collectionNaming?.filenameTemplate ?? assets?.filenameTemplate,
+    },
+    processing: assets?.images,
+  }
+}
+
+/**
+ * Save the image on the clipboard to the assets directory
+ * @param projectPath - Path to the project root
+ * @param collection - Name of the collection
+ * @param documentPath - Path to the document the image is pasted into
+ * @returns Markdown for the saved image
+ */
+export const processPastedImage = async (
+  projectPath: string,
+  collection: string,
+  documentPath: string
+): Promise<string> => {
+  const { assetsDirectory, pathStyle, naming, processing } =
+    getAssetImportOptions(collection)
+
+  const imported = await invoke<ImportedAsset>('paste_image_from_clipboard', {
+    projectPath,
+    collection,
+    assetsDirectory,
+    targetDocument: documentPath,
+    pathStyle,
+    naming,
+    processing,
+  })
+  const path =
+    pathStyle === 'project_root' ? `/${imported.path}` : imported.path
+
+  return formatAsMarkdown('', path, true)
+}
+
 /**
  * Process a single file for drag and drop
  * @param filePath - Path to the dropped file
  * @param projectPath - Path to the project root
  * @param collection - Name of the collection
+ * @param documentPath - Path to the document the file is dropped into
  * @returns Processed file information
  */
 export const processDroppedFile = async (
   filePath: string,
   projectPath: string,
-  collection: string
+  collection: string,
+  documentPath?: string
 ): Promise<ProcessedFile> => {
   const filename = extractFilename(filePath)
   const isImage = isImageFile(filename)
 
   try {
-    // Get assets directory override from store
-    const { currentProjectSettings } = useProjectStore.getState()
-    const assetsDirectory =
-      currentProjectSettings?.pathOverrides?.assetsDirectory
+    const { assetsDirectory, pathStyle, naming, processing } =
+      getAssetImportOptions(collection)
 
+    let imported: ImportedAsset
     let newPath: string
-    if (assetsDirectory && assetsDirectory !== ASTRO_PATHS.ASSETS_DIR) {
+    if (documentPath) {
+      // The backend formats the path for the document in the chosen style
+      imported = await invoke<ImportedAsset>(
+        'copy_file_to_assets_with_override',
+        {
+          sourcePath: filePath,
+          projectPath: projectPath,
+          collection: collection,
+          assetsDirectory: assetsDirectory,
+          targetDocument: documentPath,
+          pathStyle: pathStyle,
+          naming: naming,
+          processing: processing,
+        }
+      )
+      newPath =
+        pathStyle === 'project_root' ? `/${imported.path}` : imported.path
+    } else if (assetsDirectory) {
       // Use the override
-      newPath = await invoke<string>('copy_file_to_assets_with_override', {
-        sourcePath: filePath,
-        projectPath: projectPath,
-        collection: collection,
-        assetsDirectory: assetsDirectory,
-      })
+      imported = await invoke<ImportedAsset>(
+        'copy_file_to_assets_with_override',
+        {
+          sourcePath: filePath,
+          projectPath: projectPath,
+          collection: collection,
+          assetsDirectory: assetsDirectory,
+        }
+      )
+      newPath = `/${imported.path}`
     } else {
       // Use default
-      newPath = await invoke<string>('copy_file_to_assets', {
+      imported = await invoke<ImportedAsset>('copy_file_to_assets', {
         sourcePath: filePath,
         projectPath: projectPath,
         collection: collection,
       })
+      newPath = `/${imported.path}`
     }
 
     // Return markdown formatted string with new path
-    const markdownText = formatAsMarkdown(filename, `/${newPath}`, isImage)
+    const markdownText = formatAsMarkdown(filename, newPath, isImage)
 
     return {
       originalPath: filePath,
       filename,
       isImage,
       markdownText,
+      deduplicated: imported.deduplicated,
     }
   } catch {
     // Fallback to original path if copy fails
@@ -132,16 +214,18 @@ export const processDroppedFile = async (
  * @param filePaths - Array of file paths
  * @param projectPath - Path to the project root
  * @param collection - Name of the collection
+ * @param documentPath - Path to the document the files are dropped into
  * @returns Array of processed files
  */
 export const processDroppedFiles = async (
   filePaths: string[],
   projectPath: string,
-  collection: string
+  collection: string,
+  documentPath?: string
 ): Promise<ProcessedFile[]> => {
   return Promise.all(
     filePaths.map(filePath =>
-      processDroppedFile(filePath, projectPath, collection)
+      processDroppedFile(filePath, projectPath, collection, documentPath)
     )
   )
 }
@@ -95,7 +95,8 @@ export const handleTauriFileDrop = async (
     const processedFiles = await processDroppedFiles(
       filePaths,
       projectPath!,
-      currentFile!.collection
+      currentFile!.collection,
+      currentFile!.path
     )
 
     const insertText = processedFiles.map(file => file.markdownText).join('\n')
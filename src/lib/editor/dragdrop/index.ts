@@ -22,6 +22,8 @@ export { handleTauriFileDrop, parseFileDropPayload } from './handlers'
 export {
   processDroppedFile,
   processDroppedFiles,
+  processPastedImage,
+  getAssetImportOptions,
   isImageFile,
   extractFilename,
   formatAsMarkdown,
@@ -6,11 +6,20 @@ export interface FileDropPayload {
   paths?: string[]
 }
 
+/**
+ * Result of the copy_file_to_assets commands
+ */
+export interface ImportedAsset {
+  path: string
+  deduplicated: boolean
+}
+
 export interface ProcessedFile {
   originalPath: string
   filename: string
   isImage: boolean
   markdownText: string
+  deduplicated?: boolean // Reused an identical file instead of copying
 }
 
 export interface DropResult {
@@ -1,13 +1,36 @@
 import { describe, it, expect, vi, beforeEach } from 'vitest'
 import { EditorView } from '@codemirror/view'
 import { EditorState, EditorSelection } from '@codemirror/state'
-import { handlePaste, isClipboardUrl } from './handlers'
+import { handlePaste, isClipboardUrl, isClipboardImage } from './handlers'
 
 // Mock URL detection
 vi.mock('../urls/detection', () => ({
   isValidUrl: vi.fn(),
 }))
 
+vi.mock('../dragdrop/fileProcessing', () => ({
+  processPastedImage: vi.fn(),
+}))
+
+vi.mock('../../../store/projectStore', () => ({
+  useProjectStore: { getState: () => ({ projectPath: '/project' }) },
+}))
+
+vi.mock('../../../store/editorStore', () => ({
+  useEditorStore: {
+    getState: () => ({
+      currentFile: {
+        path: '/project/src/content/blog/post.md',
+        collection: 'blog',
+      },
+    }),
+  },
+}))
+
+const mockProcessPastedImage = vi.mocked(
+  await import('../dragdrop/fileProcessing')
+).processPastedImage
+
 const mockIsValidUrl = vi.mocked(await import('../urls/detection')).isValidUrl
 
 // Mock dispatch function
@@ -322,4 +345,48 @@ describe('Paste Handlers', () => {
       expect(mockDispatch).not.toHaveBeenCalled()
     })
   })
+
+  describe('image paste', () => {
+    const imageClipboard = {
+      items: [{ kind: 'file', type: 'image/png' }],
+      getData: vi.fn().mockReturnValue(''),
+    } as unknown as DataTransfer
+
+    it('should detect image data on the clipboard', () => {
+      expect(isClipboardImage(imageClipboard)).toBe(true)
+      expect(isClipboardImage(null)).toBe(false)
+      expect(
+        isClipboardImage({
+          items: [{ kind: 'string', type: 'text/plain' }],
+        } as unknown as DataTransfer)
+      ).toBe(false)
+    })
+
+    it('should save pasted images and insert them as markdown', async () => {
+      const view = createMockView('Hello world', { from: 5, to: 5 })
+      const event = { clipboardData: imageClipboard } as ClipboardEvent
+      mockProcessPastedImage.mockResolvedValue(
+        '![](../../assets/blog/pasted-image.png)'
+      )
+
+      const result = handlePaste(view, event)
+
+      expect(result).toBe(true)
+      expect(mockProcessPastedImage).toHaveBeenCalledWith(
+        '/project',
+        'blog',
+        '/project/src/content/blog/post.md'
+      )
+      await vi.waitFor(() => {
+        expect(mockDispatch).toHaveBeenCalledWith({
+          changes: {
+            from: 5,
+            to: 5,
+            insert: '![](../../assets/blog/pasted-image.png)',
+          },
+          selection: { anchor: 44 },
+        })
+      })
+    })
+  })
 })
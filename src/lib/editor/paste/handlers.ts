@@ -1,9 +1,58 @@
 import { EditorView } from '@codemirror/view'
 import { EditorSelection } from '@codemirror/state'
 import { isValidUrl } from '../urls/detection'
+import { processPastedImage } from '../dragdrop/fileProcessing'
+import { useEditorStore } from '../../../store/editorStore'
+import { useProjectStore } from '../../../store/projectStore'
 
 /**
- * Handle paste events to create markdown links when pasting URLs over selected text
+ * Check if clipboard data holds an image (e.g. a screenshot) rather than text
+ * @param clipboardData - Data from the paste event
+ * @returns true if the clipboard contains image data
+ */
+export const isClipboardImage = (
+  clipboardData: DataTransfer | null | undefined
+): boolean => {
+  return Array.from(clipboardData?.items ?? []).some(
+    item => item.kind === 'file' && item.type.startsWith('image/')
+  )
+}
+
+/**
+ * Save a pasted image to the assets directory and insert it as markdown
+ * @param view - CodeMirror editor view
+ * @returns true if the paste was taken over, false when there is no open file
+ */
+const handleImagePaste = (view: EditorView): boolean => {
+  const { projectPath } = useProjectStore.getState()
+  const { currentFile } = useEditorStore.getState()
+  if (!projectPath || !currentFile) {
+    return false
+  }
+
+  void processPastedImage(
+    projectPath,
+    currentFile.collection,
+    currentFile.path
+  )
+    .then(markdownText => {
+      const { from, to } = view.state.selection.main
+      view.dispatch({
+        changes: { from, to, insert: markdownText },
+        selection: { anchor: from + markdownText.length },
+      })
+    })
+    .catch((error: unknown) => {
+      // eslint-disable-next-line no-console
+      console.error('Failed to paste image:', error)
+    })
+
+  return true
+}
+
+/**
+ * Handle paste events: images are saved to the assets directory, and URLs
+ * pasted over selected text become markdown links
  * @param view - CodeMirror editor view
  * @param event - ClipboardEvent from paste
  * @returns true if the event was handled, false to allow default behavior
@@ -12,6 +61,10 @@ export const handlePaste = (
   view: EditorView,
   event: ClipboardEvent
 ): boolean => {
+  if (isClipboardImage(event.clipboardData)) {
+    return handleImagePaste(view)
+  }
+
   const clipboardText = event.clipboardData?.getData('text/plain')
   if (!clipboardText || !isValidUrl(clipboardText.trim())) {
     return false // Let default paste behavior handle non-URLs
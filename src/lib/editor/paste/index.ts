@@ -6,6 +6,7 @@
  * - URL detection in clipboard content
  * - Automatic markdown link creation when pasting URLs over selected text
  * - Integration with existing URL utilities
+ * - Pasted images (e.g. screenshots) saved to the assets directory
  *
  * Usage:
  * ```typescript
@@ -19,4 +20,4 @@
  * ```
  */
 
-export { handlePaste, isClipboardUrl } from './handlers'
+export { handlePaste, isClipboardUrl, isClipboardImage } from './handlers'
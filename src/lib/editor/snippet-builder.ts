@@ -1,5 +1,15 @@
 import { MdxComponent } from '../../hooks/queries/useMdxComponentsQuery'
 
+/**
+ * Reads a quoted string literal like `'info'` or `"info"`
+ * @param value Source text of a default value
+ * @returns The string, or undefined when the value isn't a string literal
+ */
+const stringLiteral = (value?: string | null): string | undefined => {
+  const match = value?.trim().match(/^(['"`])(.*)\1$/)
+  return match?.[2]
+}
+
 /**
  * Builds an MDX component snippet string for insertion using CodeMirror's snippet system
  * @param component The MDX component to build
@@ -15,9 +25,10 @@ export function buildSnippet(
   const propsString = component.props
     .filter(p => enabledProps.has(p.name))
     .map(p => {
-      // For props with specific values (like 'warning' | 'info'), use the first value as default
-      let defaultValue = ''
-      if (p.prop_type.includes('|')) {
+      // Use the component's own default when it's a string, otherwise the
+      // first value of props with specific values (like 'warning' | 'info')
+      let defaultValue = stringLiteral(p.default_value) ?? ''
+      if (!defaultValue && p.prop_type.includes('|')) {
         // Extract first literal value from union type
         const firstLiteral = p.prop_type.split('|')[0]?.trim()
         if (firstLiteral?.startsWith("'") && firstLiteral?.endsWith("'")) {
@@ -30,11 +41,16 @@ export function buildSnippet(
     })
     .join(' ')
 
+  // Interactive framework components need a client directive to hydrate
+  const attributes = [component.client_directive, propsString]
+    .filter(Boolean)
+    .join(' ')
+
   if (component.has_slot) {
-    const propsPrefix = propsString ? ' ' + propsString : ''
+    const propsPrefix = attributes ? ' ' + attributes : ''
     return `<${component.name}${propsPrefix}>\${${placeholderIndex}}</${component.name}>\${}`
   }
 
-  const propsPrefix = propsString ? ' ' + propsString : ''
+  const propsPrefix = attributes ? ' ' + attributes : ''
   return `<${component.name}${propsPrefix} />\${}`
 }
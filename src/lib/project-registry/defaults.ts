@@ -4,18 +4,16 @@
 
 import { GlobalSettings, ProjectSettings, ProjectRegistry } from './types'
 
+/**
+ * Settings file format, matching SETTINGS_VERSION in src-tauri/src/commands/settings.rs.
+ * Version 1 saved the default directories and frontmatter mappings into every project.
+ */
+export const SETTINGS_VERSION = 2
+
+// Unset directories and mappings come from astro.config or the standard Astro paths
 export const DEFAULT_PROJECT_SETTINGS: ProjectSettings = {
-  pathOverrides: {
-    contentDirectory: 'src/content/',
-    assetsDirectory: 'src/assets/',
-    mdxComponentsDirectory: 'src/components/mdx/',
-  },
-  frontmatterMappings: {
-    publishedDate: 'date', // Will try date, pubDate, publishedDate
-    title: 'title',
-    description: 'description',
-    draft: 'draft',
-  },
+  pathOverrides: {},
+  frontmatterMappings: {},
   collectionViewSettings: {},
 }
 
@@ -40,7 +38,7 @@ export const DEFAULT_GLOBAL_SETTINGS: GlobalSettings = {
     fontSize: 14, // Default editor font size in pixels
   },
   defaultProjectSettings: DEFAULT_PROJECT_SETTINGS,
-  version: 1,
+  version: SETTINGS_VERSION,
 }
 
 export const DEFAULT_PROJECT_REGISTRY: ProjectRegistry = {
@@ -21,7 +21,7 @@ import {
   saveProjectData,
 } from './persistence'
 import { discoverProject, isSameProject } from './utils'
-import { DEFAULT_PROJECT_SETTINGS } from './defaults'
+import { DEFAULT_PROJECT_SETTINGS, SETTINGS_VERSION } from './defaults'
 
 export class ProjectRegistryManager {
   private registry: ProjectRegistry | null = null
@@ -193,6 +193,7 @@ export class ProjectRegistryManager {
     const defaultData: ProjectData = {
       metadata,
       settings: { ...DEFAULT_PROJECT_SETTINGS },
+      version: SETTINGS_VERSION,
     }
 
     // Cache and save
@@ -217,6 +218,7 @@ export class ProjectRegistryManager {
     // Update settings
     projectData.settings = {
       ...projectData.settings,
+      ...settings,
       pathOverrides: {
         ...projectData.settings.pathOverrides,
         ...settings.pathOverrides,
@@ -278,6 +280,15 @@ export class ProjectRegistryManager {
         ...globalSettings.defaultProjectSettings.pathOverrides,
         ...projectData.settings.pathOverrides,
       },
+      ignorePatterns:
+        projectData.settings.ignorePatterns ??
+        globalSettings.defaultProjectSettings.ignorePatterns,
+      watcher:
+        projectData.settings.watcher ??
+        globalSettings.defaultProjectSettings.watcher,
+      assets:
+        projectData.settings.assets ??
+        globalSettings.defaultProjectSettings.assets,
       frontmatterMappings: {
         ...globalSettings.defaultProjectSettings.frontmatterMappings,
         ...projectData.settings.frontmatterMappings,
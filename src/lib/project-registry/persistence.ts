@@ -6,8 +6,72 @@
 
 import { invoke } from '@tauri-apps/api/core'
 import { error } from '@tauri-apps/plugin-log'
-import { ProjectRegistry, GlobalSettings, ProjectData } from './types'
-import { DEFAULT_PROJECT_REGISTRY, DEFAULT_GLOBAL_SETTINGS } from './defaults'
+import {
+  ProjectRegistry,
+  GlobalSettings,
+  ProjectData,
+  ProjectSettings,
+} from './types'
+import {
+  DEFAULT_PROJECT_REGISTRY,
+  DEFAULT_GLOBAL_SETTINGS,
+  SETTINGS_VERSION,
+} from './defaults'
+
+// What version 1 saved into every project, matching LEGACY_DEFAULT_DIRECTORIES and
+// LEGACY_DEFAULT_MAPPINGS in src-tauri/src/commands/settings.rs
+const LEGACY_DEFAULT_DIRECTORIES: Record<string, string> = {
+  contentDirectory: 'src/content',
+  assetsDirectory: 'src/assets',
+  mdxComponentsDirectory: 'src/components/mdx',
+}
+const LEGACY_DEFAULT_MAPPINGS: Record<string, string> = {
+  publishedDate: 'date',
+  title: 'title',
+  description: 'description',
+  draft: 'draft',
+}
+
+type PathOverrides = ProjectSettings['pathOverrides']
+type FrontmatterMappings = ProjectSettings['frontmatterMappings']
+
+const normalizeDirectory = (dir: string) =>
+  dir
+    .trim()
+    .replace(/^(\.\/)+/, '')
+    .replace(/\/+$/, '')
+
+/**
+ * Brings settings saved by an older version up to date. Version 1 saved the default
+ * directories and frontmatter mappings into every project; they're dropped so they
+ * don't hide values from astro.config or .astro-editor.json.
+ */
+export function migrateProjectSettings(
+  settings: ProjectSettings,
+  version: number | undefined
+): ProjectSettings {
+  if ((version ?? 1) >= SETTINGS_VERSION) return settings
+
+  const pathOverrides: PathOverrides = {}
+  for (const [key, dir] of Object.entries(settings.pathOverrides ?? {})) {
+    if (typeof dir !== 'string') continue
+    const normalized = normalizeDirectory(dir)
+    if (normalized && LEGACY_DEFAULT_DIRECTORIES[key] !== normalized) {
+      pathOverrides[key as keyof PathOverrides] = normalized
+    }
+  }
+
+  const frontmatterMappings: FrontmatterMappings = {}
+  for (const [key, field] of Object.entries(
+    settings.frontmatterMappings ?? {}
+  )) {
+    if (LEGACY_DEFAULT_MAPPINGS[key] !== field) {
+      frontmatterMappings[key as keyof FrontmatterMappings] = field
+    }
+  }
+
+  return { ...settings, pathOverrides, frontmatterMappings }
+}
 
 /**
  * Get the application support directory paths
@@ -131,19 +195,15 @@ export async function loadGlobalSettings(): Promise<GlobalSettings> {
           ...(settings.appearance?.headingColor || {}),
         },
       },
-      defaultProjectSettings: {
-        ...DEFAULT_GLOBAL_SETTINGS.defaultProjectSettings,
-        ...(settings.defaultProjectSettings || {}),
-        pathOverrides: {
-          ...DEFAULT_GLOBAL_SETTINGS.defaultProjectSettings.pathOverrides,
-          ...(settings.defaultProjectSettings?.pathOverrides || {}),
-        },
-        frontmatterMappings: {
-          ...DEFAULT_GLOBAL_SETTINGS.defaultProjectSettings.frontmatterMappings,
-          ...(settings.defaultProjectSettings?.frontmatterMappings || {}),
+      defaultProjectSettings: migrateProjectSettings(
+        {
+          ...DEFAULT_GLOBAL_SETTINGS.defaultProjectSettings,
+          ...(settings.defaultProjectSettings || {}),
         },
-      },
-      version: settings.version || DEFAULT_GLOBAL_SETTINGS.version,
+        settings.version
+      ),
+      // Saved back as the current version, so the migration only runs once
+      version: SETTINGS_VERSION,
     }
   } catch {
     // File doesn't exist or is invalid, return defaults
@@ -186,7 +246,12 @@ export async function loadProjectData(
       filePath: projectFilePath,
     })
 
-    return JSON.parse(content) as ProjectData
+    const data = JSON.parse(content) as ProjectData
+    return {
+      ...data,
+      settings: migrateProjectSettings(data.settings, data.version),
+      version: SETTINGS_VERSION,
+    }
   } catch {
     // File doesn't exist or is invalid
     return null
@@ -12,6 +12,29 @@ export interface ProjectMetadata {
   created: string // ISO timestamp
 }
 
+export type AssetPathStyle =
+  | 'relative' // ../../assets/blog/photo.png
+  | 'alias' // @assets/blog/photo.png, from tsconfig.json paths
+  | 'public_url' // /images/photo.png, for files in public/
+  | 'project_root' // /src/assets/blog/photo.png
+
+// Templates for where dropped files are copied, using the tokens {collection},
+// {slug}, {date}, {hash}, {name} and {ext}. A folder starting with ./ is next to
+// the document.
+export interface AssetNaming {
+  folderTemplate?: string // Default: {collection}, inside the assets directory
+  filenameTemplate?: string // Default: {date}-{name}.{ext}
+}
+
+// Applied to JPEG, PNG and WebP images as they are imported
+export interface ImageProcessing {
+  maxWidth?: number
+  maxHeight?: number
+  stripMetadata?: boolean // EXIF (including GPS location), XMP and text
+  convertTo?: 'webp' | 'avif' // WebP output is lossless
+  quality?: number // 1-100, for JPEG and AVIF
+}
+
 export interface ProjectSettings {
   // Project-specific overrides for paths
   pathOverrides: {
@@ -19,6 +42,20 @@ export interface ProjectSettings {
     assetsDirectory?: string
     mdxComponentsDirectory?: string
   }
+  // Extra paths to skip when scanning and watching, in .gitignore syntax
+  ignorePatterns?: string[]
+  // File watching - 'auto' polls on network drives or when native watching fails
+  watcher?: {
+    mode?: 'auto' | 'native' | 'poll'
+    pollIntervalMs?: number
+  }
+  // How dropped files are referenced - 'relative' to the document by default
+  assets?: AssetNaming & {
+    pathStyle?: AssetPathStyle
+    // Per-collection naming, taking precedence over the project's
+    collections?: { [collectionName: string]: AssetNaming }
+    images?: ImageProcessing
+  }
   // Project-specific overrides for frontmatter field mappings
   frontmatterMappings: {
     publishedDate?: string
@@ -37,6 +74,7 @@ export interface ProjectSettings {
 export interface ProjectData {
   metadata: ProjectMetadata
   settings: ProjectSettings
+  version?: number // Missing in files saved before settings were versioned
 }
 
 export interface ProjectRegistry {
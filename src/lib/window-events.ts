@@ -0,0 +1,12 @@
+import { listen, type EventCallback } from '@tauri-apps/api/event'
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow'
+
+/**
+ * Listen for an event sent to the current window.
+ *
+ * Each window has its own project, so menu actions and file watcher events are
+ * routed to a single window by the backend. A plain `listen` would also receive
+ * events meant for other windows.
+ */
+export const listenToWindow = <T>(event: string, handler: EventCallback<T>) =>
+  listen<T>(event, handler, { target: getCurrentWebviewWindow().label })
@@ -16,6 +16,11 @@ export interface FileEntry {
   collection: string
   last_modified?: number
   frontmatter?: Record<string, unknown>
+  // Translation info, only set in collections that have locales
+  locale?: string | null
+  translation_key?: string | null
+  missing_translations?: string[]
+  outdated_translation?: boolean
 }
 
 export interface MarkdownContent {
@@ -6,6 +6,7 @@ interface PropInfo {
   prop_type: string
   is_optional: boolean
   default_value?: string | null
+  description?: string | null
 }
 
 interface MdxComponent {
@@ -14,6 +15,8 @@ interface MdxComponent {
   props: PropInfo[]
   has_slot: boolean
   description?: string | null
+  framework: 'astro' | 'react' | 'vue' | 'svelte'
+  client_directive?: string | null // e.g. 'client:load'
 }
 
 interface MdxComponentsState {
@@ -1,10 +1,13 @@
 import { create } from 'zustand'
 import { invoke } from '@tauri-apps/api/core'
-import { listen } from '@tauri-apps/api/event'
+import type { UnlistenFn } from '@tauri-apps/api/event'
 import { error as logError, info, debug } from '@tauri-apps/plugin-log'
 import { toast } from '../lib/toast'
-import { ASTRO_PATHS } from '../lib/constants'
 import { formatErrorForLogging } from '../lib/diagnostics'
+import { listenToWindow } from '../lib/window-events'
+import { queryClient } from '../lib/query-client'
+import { queryKeys } from '../lib/query-keys'
+import { ASTRO_PATHS } from '../lib/constants'
 import {
   projectRegistryManager,
   GlobalSettings,
@@ -12,12 +15,85 @@ import {
 } from '../lib/project-registry'
 import { useEditorStore } from './editorStore'
 
+// An Astro site inside an opened folder (see detect_astro_sites)
+export interface AstroSite {
+  path: string
+  relative_path: string
+  name: string
+  config_file: string
+  workspace_member: boolean
+}
+
+// File watcher event subscriptions, removed when the watcher stops or restarts
+let watcherListeners: Promise<UnlistenFn>[] = []
+
+const clearWatcherListeners = async () => {
+  const listeners = watcherListeners
+  watcherListeners = []
+  const unlisteners = await Promise.all(listeners)
+  unlisteners.forEach(unlisten => unlisten())
+}
+
+/**
+ * Resolves an opened folder to the Astro site to load. Returns null when the folder
+ * holds several sites and the user has to pick one.
+ */
+const resolveAstroSite = async (
+  path: string
+): Promise<{ sitePath: string | null; sites: AstroSite[] }> => {
+  try {
+    const sites = await invoke<AstroSite[]>('detect_astro_sites', {
+      rootPath: path,
+    })
+    // The folder is a site itself, or not an Astro project we can detect
+    if (sites.length === 0 || sites.some(site => site.relative_path === '')) {
+      return { sitePath: path, sites }
+    }
+    return {
+      sitePath: sites.length === 1 ? (sites[0]?.path ?? null) : null,
+      sites,
+    }
+  } catch (error) {
+    await debug(
+      `Astro Editor [PROJECT_SETUP] Site detection failed, opening folder as is: ${String(error)}`
+    )
+    return { sitePath: path, sites: [] }
+  }
+}
+
+/**
+ * Effective settings from the backend, which also applies the project's committed
+ * .astro-editor.json. Falls back to the registry's own merge if that fails.
+ */
+const loadEffectiveSettings = async (
+  projectId: string,
+  projectPath: string
+): Promise<ProjectSettings> => {
+  try {
+    return await invoke<ProjectSettings>('get_effective_project_settings', {
+      projectPath,
+      projectId,
+    })
+  } catch (error) {
+    toast.warning('Project settings file is invalid', {
+      description: String(error),
+    })
+    await logError(
+      `Astro Editor [PROJECT_SETUP] Failed to load project settings: ${String(error)}`
+    )
+    return projectRegistryManager.getEffectiveSettings(projectId)
+  }
+}
+
 interface ProjectState {
   // Core identifiers
   projectPath: string | null
   currentProjectId: string | null
   selectedCollection: string | null
 
+  // Sites found when the opened folder is a monorepo, waiting for the user to pick one
+  siteCandidates: AstroSite[] | null
+
   // Settings
   globalSettings: GlobalSettings | null
   currentProjectSettings: ProjectSettings | null
@@ -25,6 +101,7 @@ interface ProjectState {
   // Actions
   setProject: (path: string) => void
   setSelectedCollection: (collection: string | null) => void
+  clearSiteCandidates: () => void
   loadPersistedProject: () => Promise<void>
   initializeProjectRegistry: () => Promise<void>
   updateGlobalSettings: (settings: Partial<GlobalSettings>) => Promise<void>
@@ -38,12 +115,21 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
   projectPath: null,
   currentProjectId: null,
   selectedCollection: null,
+  siteCandidates: null,
   globalSettings: null,
   currentProjectSettings: null,
 
   // Actions
-  setProject: (path: string) => {
+  setProject: (folderPath: string) => {
     void (async () => {
+      // Opening a monorepo root loads the Astro site inside it
+      const { sitePath: path, sites } = await resolveAstroSite(folderPath)
+      if (!path) {
+        set({ siteCandidates: sites })
+        return
+      }
+      set({ siteCandidates: null })
+
       try {
         await info(
           `Astro Editor [PROJECT_SETUP] Starting project setup: ${path}`
@@ -63,8 +149,7 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
         await info(
           `Astro Editor [PROJECT_SETUP] Loading project settings for: ${projectId}`
         )
-        const projectSettings =
-          await projectRegistryManager.getEffectiveSettings(projectId)
+        const projectSettings = await loadEffectiveSettings(projectId, path)
 
         set({
           projectPath: path,
@@ -104,26 +189,46 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
     set({ selectedCollection: collection })
   },
 
+  clearSiteCandidates: () => {
+    set({ siteCandidates: null })
+  },
+
   startFileWatcher: async () => {
     const { projectPath, currentProjectSettings } = get()
     if (!projectPath) return
 
+    // Restarting the watcher must not leave the previous subscriptions behind
+    await clearWatcherListeners()
+
     try {
-      // Use path override if configured
+      // Use path overrides and ignore patterns if configured
       const contentDirectory =
         currentProjectSettings?.pathOverrides?.contentDirectory
+      const mdxDirectory =
+        currentProjectSettings?.pathOverrides?.mdxComponentsDirectory
+      const ignorePatterns = currentProjectSettings?.ignorePatterns
+
+      const watcherInfo = await invoke<{
+        mode: 'native' | 'poll'
+        poll_interval_ms: number | null
+        fallback_reason: string | null
+      }>('start_watching_project_with_content_dir', {
+        projectPath,
+        contentDirectory,
+        mdxDirectory,
+        ignorePatterns,
+        watcherMode: currentProjectSettings?.watcher?.mode,
+        pollIntervalMs: currentProjectSettings?.watcher?.pollIntervalMs,
+      })
 
-      if (contentDirectory && contentDirectory !== ASTRO_PATHS.CONTENT_DIR) {
-        await invoke('start_watching_project_with_content_dir', {
-          projectPath,
-          contentDirectory,
-        })
-      } else {
-        await invoke('start_watching_project', { projectPath })
+      if (watcherInfo.fallback_reason) {
+        await info(
+          `Astro Editor [PROJECT_SETUP] Polling for file changes every ${watcherInfo.poll_interval_ms}ms: ${watcherInfo.fallback_reason}`
+        )
       }
 
       // Listen for file change events
-      const unlistenFileChanged = listen(
+      const unlistenFileChanged = listenToWindow(
         'file-changed',
         (event: { payload: unknown }) => {
           // File refresh is now handled by TanStack Query invalidation
@@ -138,8 +243,52 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
         }
       )
 
-      // Store the unlisten function for cleanup (though we don't currently clean it up)
-      void unlistenFileChanged
+      // A file was renamed outside the app - keep the open editor pointed at it
+      const unlistenFileRenamed = listenToWindow(
+        'file-renamed',
+        (event: { payload: { from: string; to: string } }) => {
+          const { currentFile, updateCurrentFileAfterRename } =
+            useEditorStore.getState()
+          if (currentFile?.path === event.payload.from) {
+            updateCurrentFileAfterRename(event.payload.to)
+          }
+          void queryClient.invalidateQueries({
+            queryKey: queryKeys.collections(projectPath),
+          })
+        }
+      )
+
+      // Content config or MDX components changed on disk - the backend sends the rescanned data
+      const unlistenSchemaChanged = listenToWindow(
+        'schema-changed',
+        (event: { payload: unknown }) => {
+          queryClient.setQueryData(
+            queryKeys.collections(projectPath),
+            event.payload
+          )
+        }
+      )
+      const unlistenComponentsChanged = listenToWindow(
+        'components-changed',
+        (event: { payload: unknown }) => {
+          queryClient.setQueryData(
+            queryKeys.mdxComponents(
+              projectPath,
+              mdxDirectory !== ASTRO_PATHS.MDX_COMPONENTS_DIR
+                ? mdxDirectory
+                : undefined
+            ),
+            event.payload
+          )
+        }
+      )
+
+      watcherListeners = [
+        unlistenFileChanged,
+        unlistenFileRenamed,
+        unlistenSchemaChanged,
+        unlistenComponentsChanged,
+      ]
     } catch (error) {
       const errorMsg = formatErrorForLogging(
         'PROJECT_SETUP',
@@ -158,6 +307,8 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
     const { projectPath } = get()
     if (!projectPath) return
 
+    await clearWatcherListeners()
+
     try {
       await invoke('stop_watching_project', { projectPath })
     } catch (error) {
@@ -178,6 +329,13 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
     try {
       await get().initializeProjectRegistry()
 
+      // Windows opened for a specific project load that instead of the last one
+      const windowProject = await invoke<string | null>('get_window_project')
+      if (windowProject) {
+        get().setProject(windowProject)
+        return
+      }
+
       // Try to load the last opened project from registry
       const lastProjectId = projectRegistryManager.getLastOpenedProjectId()
       if (lastProjectId) {
@@ -267,8 +425,8 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
   },
 
   updateProjectSettings: async (settings: Partial<ProjectSettings>) => {
-    const { currentProjectId } = get()
-    if (!currentProjectId) {
+    const { currentProjectId, projectPath } = get()
+    if (!currentProjectId || !projectPath) {
       toast.error('No project is currently open')
       return
     }
@@ -278,8 +436,10 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
         currentProjectId,
         settings
       )
-      const updatedSettings =
-        await projectRegistryManager.getEffectiveSettings(currentProjectId)
+      const updatedSettings = await loadEffectiveSettings(
+        currentProjectId,
+        projectPath
+      )
       set({ currentProjectSettings: updatedSettings })
     } catch (error) {
       toast.error('Failed to update project settings', {
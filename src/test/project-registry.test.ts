@@ -11,7 +11,11 @@ vi.unmock('../lib/project-registry/persistence')
 vi.unmock('../lib/project-registry/utils')
 
 import { ProjectRegistryManager } from '../lib/project-registry'
-import { DEFAULT_PROJECT_SETTINGS } from '../lib/project-registry/defaults'
+import {
+  DEFAULT_PROJECT_SETTINGS,
+  SETTINGS_VERSION,
+} from '../lib/project-registry/defaults'
+import { migrateProjectSettings } from '../lib/project-registry/persistence'
 
 // Mock Tauri invoke for testing
 vi.mock('@tauri-apps/api/core', () => ({
@@ -60,7 +64,7 @@ describe('Project Registry System', () => {
     expect(
       globalSettings.defaultProjectSettings.frontmatterMappings
     ).toBeDefined()
-    expect(globalSettings.version).toBe(1)
+    expect(globalSettings.version).toBe(SETTINGS_VERSION)
 
     expect(registry.projects).toEqual({})
     expect(registry.lastOpenedProject).toBeNull()
@@ -167,4 +171,25 @@ describe('Project Registry System', () => {
     expect(manager.getRegistry().projects[newProjectId]).toBeDefined()
     expect(manager.getRegistry().projects[newProjectId]?.path).toBe(newPath)
   })
+
+  it('should drop the defaults version 1 saved into every project', () => {
+    const legacy = {
+      pathOverrides: {
+        contentDirectory: 'src/content/',
+        assetsDirectory: './public/images/',
+        mdxComponentsDirectory: 'src/components/mdx/',
+      },
+      frontmatterMappings: { publishedDate: 'date', title: 'heading' },
+      collectionViewSettings: { blog: { showDraftsOnly: true } },
+    }
+
+    expect(migrateProjectSettings(legacy, undefined)).toEqual({
+      pathOverrides: { assetsDirectory: 'public/images' },
+      frontmatterMappings: { title: 'heading' },
+      collectionViewSettings: { blog: { showDraftsOnly: true } },
+    })
+
+    // Current settings are kept as written, even when they match the old defaults
+    expect(migrateProjectSettings(legacy, SETTINGS_VERSION)).toBe(legacy)
+  })
 })
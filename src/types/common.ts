@@ -54,3 +54,38 @@ export interface CollectionMeta {
   fieldCount: number
   hasSchema: boolean
 }
+
+// Results of the get_file_stats and get_collection_stats commands
+export interface ContentStats {
+  word_count: number
+  character_count: number // Non-whitespace characters
+  reading_time_minutes: number
+  heading_count: number
+  image_count: number
+  link_count: number
+}
+
+export interface FileStats {
+  id: string
+  path: string
+  is_draft: boolean
+  published_month: string | null // "YYYY-MM"
+  stats: ContentStats
+}
+
+export interface MonthlyCount {
+  month: string // "YYYY-MM"
+  count: number
+}
+
+export interface CollectionStats {
+  collection: string
+  file_count: number
+  draft_count: number
+  published_count: number
+  total_words: number
+  average_words: number
+  total_reading_time_minutes: number
+  files: FileStats[]
+  publishing_cadence: MonthlyCount[] // Published files per month, oldest first
+}